@@ -0,0 +1,219 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ops::Add;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A global allocator that counts the allocations of the whole process, on top of the system
+/// allocator. Allocations from every thread are counted, including those of a thread pool like
+/// rayon's, so the counts of closures running at the same time are mixed.
+///
+/// It has to be installed by the binary to be used, after which [`measure`] reports what a
+/// closure allocated:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: aoc_common::CountingAllocator = aoc_common::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn record_alloc(size: usize) {
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    record_grow(size);
+}
+
+fn record_grow(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_shrink(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_shrink(layout.size());
+    }
+
+    /// A reallocation counts as an allocation of the bytes it adds.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            if new_size >= layout.size() {
+                BYTES.fetch_add(new_size - layout.size(), Ordering::Relaxed);
+                record_grow(new_size - layout.size());
+            } else {
+                record_shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// What was allocated while running a closure, see [`measure`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations and reallocations.
+    pub count: usize,
+    /// Total bytes allocated, whether they were freed or not.
+    pub bytes: usize,
+    /// Highest amount of memory in use at once, above what was in use before.
+    pub peak: usize,
+}
+
+/// The counts and bytes of two runs are added up, while the peak is the highest of the two.
+impl Add for AllocStats {
+    type Output = AllocStats;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        AllocStats {
+            count: self.count + rhs.count,
+            bytes: self.bytes + rhs.bytes,
+            peak: self.peak.max(rhs.peak),
+        }
+    }
+}
+
+/// Run `f` and report what the process allocated in the meantime, on any thread.
+///
+/// The stats are all zeros unless [`CountingAllocator`] is the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let current = CURRENT.load(Ordering::Relaxed);
+    let peak = PEAK.swap(current, Ordering::Relaxed);
+
+    let res = f();
+
+    let stats = AllocStats {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(current),
+    };
+    // Keep the peak of an enclosing measure.
+    PEAK.fetch_max(peak, Ordering::Relaxed);
+
+    (res, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+    use std::sync::Mutex;
+    use std::thread;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: CountingAllocator = CountingAllocator;
+
+    /// Taken by the tests calling [`measure`], which would reset each other's peak.
+    static MEASURING: Mutex<()> = Mutex::new(());
+
+    // The other tests allocate at the same time, so the stats can only be bounded from below.
+
+    #[rstest]
+    fn test_measure() {
+        let _lock = MEASURING.lock().unwrap();
+        let (_, stats) = measure(|| {
+            let a = black_box(vec![0u8; 1000]);
+            let b = black_box(vec![0u8; 500]);
+            drop((a, b));
+            black_box(vec![0u8; 100]);
+        });
+
+        assert!(stats.count >= 3, "{:?}", stats);
+        assert!(stats.bytes >= 1600, "{:?}", stats);
+        assert!(stats.peak >= 1500, "{:?}", stats);
+    }
+
+    #[rstest]
+    fn test_measure_other_threads() {
+        let _lock = MEASURING.lock().unwrap();
+        let (_, stats) = measure(|| {
+            thread::scope(|s| {
+                s.spawn(|| black_box(vec![0u8; 1 << 20]));
+            })
+        });
+
+        assert!(stats.bytes >= 1 << 20, "{:?}", stats);
+        assert!(stats.peak >= 1 << 20, "{:?}", stats);
+    }
+
+    #[rstest]
+    fn test_measure_nested() {
+        let _lock = MEASURING.lock().unwrap();
+        let (inner, outer) = measure(|| {
+            let a = black_box(vec![0u8; 1000]);
+            drop(a);
+
+            measure(|| black_box(vec![0u8; 200])).1
+        });
+
+        assert!(inner.bytes >= 200, "{:?}", inner);
+        assert!(outer.count > inner.count, "{:?} {:?}", outer, inner);
+        assert!(outer.bytes >= inner.bytes + 1000, "{:?} {:?}", outer, inner);
+        assert!(outer.peak >= 1000, "{:?}", outer);
+    }
+
+    #[rstest]
+    fn test_measure_realloc() {
+        let _lock = MEASURING.lock().unwrap();
+        let (_, stats) = measure(|| {
+            let mut v = black_box(Vec::<u8>::with_capacity(100));
+            v.reserve_exact(300);
+            black_box(v);
+        });
+
+        assert!(stats.count >= 2, "{:?}", stats);
+        assert!(stats.bytes >= 300, "{:?}", stats);
+    }
+
+    #[rstest]
+    fn test_add() {
+        let a = AllocStats {
+            count: 1,
+            bytes: 10,
+            peak: 8,
+        };
+        let b = AllocStats {
+            count: 2,
+            bytes: 5,
+            peak: 5,
+        };
+
+        assert_eq!(
+            a + b,
+            AllocStats {
+                count: 3,
+                bytes: 15,
+                peak: 8
+            }
+        );
+    }
+}
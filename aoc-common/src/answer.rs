@@ -0,0 +1,78 @@
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    /// An integer that doesn't fit in an `i64`.
+    BigInt(i128),
+    Str(String),
+    /// The part hasn't been solved yet.
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(i) => i.fmt(f),
+            Answer::BigInt(i) => i.fmt(f),
+            Answer::Str(s) => s.fmt(f),
+            Answer::Unsolved => f.pad("unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(i) => Answer::Int(i),
+                        Err(_) => Answer::BigInt(value as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Answer::from(42u32), Answer::Int(42))]
+    #[case(Answer::from(-3i64), Answer::Int(-3))]
+    #[case(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128))]
+    #[case(Answer::from(7i128), Answer::Int(7))]
+    #[case(Answer::from("6,0,6"), Answer::Str("6,0,6".to_owned()))]
+    fn test_from(#[case] answer: Answer, #[case] expected: Answer) {
+        assert_eq!(answer, expected);
+    }
+
+    #[rstest]
+    #[case(Answer::Int(-3), "-3")]
+    #[case(Answer::BigInt(u64::MAX as i128 + 1), "18446744073709551616")]
+    #[case(Answer::Str("6,0,6".to_owned()), "6,0,6")]
+    #[case(Answer::Unsolved, "unsolved")]
+    fn test_display(#[case] answer: Answer, #[case] expected: &str) {
+        assert_eq!(answer.to_string(), expected);
+    }
+}
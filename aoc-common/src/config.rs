@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+/// Environment variable overriding the path of the configuration file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Environment variable overriding the directory of the files written by the tools.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Year of the puzzles when none is configured.
+pub const DEFAULT_YEAR: u16 = 2024;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Settings of the workspace, as stored in `aoc.toml` at its root:
+///
+/// ```toml
+/// year = 2024
+/// input-dir = "input"
+/// log = "info,day14=debug"
+///
+/// [bench]
+/// warmup = 5
+/// budget = "10s"
+///
+/// [params.2024.day14]
+/// width = 11
+/// height = 7
+/// ```
+///
+/// Every setting is optional. Command line options take precedence over environment variables,
+/// which take precedence over this file. Relative paths are relative to the file's directory.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    pub year: Option<u16>,
+    /// Directory containing the puzzle inputs.
+    pub input_dir: Option<PathBuf>,
+    /// Directory of the files written by the tools, such as the benchmark history.
+    pub cache_dir: Option<PathBuf>,
+    /// File containing the session cookie of the Advent of Code website.
+    pub session_file: Option<PathBuf>,
+    /// Default tracing filter, in the syntax of `RUST_LOG`.
+    pub log: Option<String>,
+    #[serde(default)]
+    pub bench: BenchConfig,
+    /// Overrides of the puzzle parameters, by year, day (`day14`) then name.
+    #[serde(default)]
+    params: BTreeMap<String, BTreeMap<String, BTreeMap<String, i64>>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BenchConfig {
+    pub warmup: Option<usize>,
+    /// Time to spend measuring each day, e.g. `500ms` or `5s`.
+    pub budget: Option<String>,
+}
+
+impl Config {
+    /// Path of the configuration file: `$AOC_CONFIG` if set, otherwise `aoc.toml` at the root of
+    /// the workspace.
+    pub fn default_path() -> PathBuf {
+        match std::env::var_os(CONFIG_VAR) {
+            Some(path) if !path.is_empty() => PathBuf::from(path),
+            _ => workspace_root().join("aoc.toml"),
+        }
+    }
+
+    /// Load the configuration from `path`. A missing file holds no settings.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let s = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("unable to read {}: {}", path.display(), e)),
+        };
+        let config = Self::parse(&s).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        Ok(config.relative_to(path.parent().unwrap_or(Path::new("."))))
+    }
+
+    fn parse(s: &str) -> Result<Self, String> {
+        let config: Config = toml::from_str(s).map_err(|e| e.to_string())?;
+
+        for (year, days) in &config.params {
+            if year.parse::<u16>().is_err() {
+                return Err(format!(
+                    "invalid year {:?} in params, expected e.g. `2024`",
+                    year
+                ));
+            }
+            if let Some(key) = days.keys().find(|k| day_number(k).is_none()) {
+                return Err(format!("invalid day {:?}, expected e.g. `day07`", key));
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn relative_to(mut self, dir: &Path) -> Self {
+        for path in [
+            &mut self.input_dir,
+            &mut self.cache_dir,
+            &mut self.session_file,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *path = dir.join(&*path);
+        }
+
+        self
+    }
+
+    /// Load the configuration used by the rest of the crate from `path`, or from the default path
+    /// if `None`. Unlike the default file, an explicit one must exist.
+    ///
+    /// Must be called before anything reads the configuration.
+    pub fn init(path: Option<&Path>) -> Result<&'static Config, String> {
+        let loaded = match path {
+            Some(p) if !p.exists() => return Err(format!("{} doesn't exist", p.display())),
+            Some(p) => Self::load(p)?,
+            None => Self::load(&Self::default_path())?,
+        };
+
+        CONFIG
+            .set(loaded)
+            .map_err(|_| "the configuration is already loaded".to_owned())?;
+
+        Ok(config())
+    }
+
+    /// Parameter overrides of `day` of `year`.
+    pub fn params(&self, year: u16, day: u8) -> impl Iterator<Item = (&str, i64)> {
+        self.params
+            .iter()
+            .filter(move |(key, _)| key.parse() == Ok(year))
+            .flat_map(|(_, days)| days.iter())
+            .filter(move |(key, _)| day_number(key) == Some(day))
+            .flat_map(|(_, params)| params.iter().map(|(name, value)| (name.as_str(), *value)))
+    }
+}
+
+/// The configuration of the workspace, loaded from the default path unless [`Config::init`] was
+/// called first.
+///
+/// Panics if the file is invalid.
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(|| Config::load(&Config::default_path()).unwrap_or_else(|e| panic!("{}", e)))
+}
+
+/// Directory of the files written by the tools, such as the benchmark history: `$AOC_CACHE_DIR`
+/// if set, otherwise the one set in the configuration, otherwise the root of the workspace.
+pub fn cache_dir() -> PathBuf {
+    layered_path(
+        std::env::var_os(CACHE_DIR_VAR),
+        config().cache_dir.as_deref(),
+        || workspace_root().to_owned(),
+    )
+}
+
+/// Pick a path from a non-empty environment variable, falling back to the configuration, then to
+/// `default`.
+pub(crate) fn layered_path(
+    var: Option<OsString>,
+    configured: Option<&Path>,
+    default: impl FnOnce() -> PathBuf,
+) -> PathBuf {
+    match (var, configured) {
+        (Some(var), _) if !var.is_empty() => PathBuf::from(var),
+        (_, Some(path)) => path.to_owned(),
+        _ => default(),
+    }
+}
+
+pub(crate) fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-common is in a workspace")
+}
+
+fn day_number(key: &str) -> Option<u8> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+            year = 2023
+            input-dir = "inputs"
+            log = "debug"
+
+            [bench]
+            warmup = 5
+
+            [params.2024.day14]
+            width = 11
+            height = 7
+            "#,
+        )
+        .unwrap()
+        .relative_to(Path::new("/aoc"));
+
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.input_dir, Some(PathBuf::from("/aoc/inputs")));
+        assert_eq!(config.cache_dir, None);
+        assert_eq!(config.log.as_deref(), Some("debug"));
+        assert_eq!(config.bench.warmup, Some(5));
+        assert_eq!(config.bench.budget, None);
+        assert_eq!(
+            config.params(2024, 14).collect::<Vec<_>>(),
+            vec![("height", 7), ("width", 11)]
+        );
+        assert_eq!(config.params(2024, 11).count(), 0);
+        assert_eq!(config.params(2023, 14).count(), 0);
+    }
+
+    #[rstest]
+    #[case("[params.2024.foo]\nwidth = 11")]
+    #[case("[params.day14]\nwidth = 11")]
+    #[case("[params.2024.day14]\nwidth = \"11\"")]
+    #[case("[foo]\nbar = 1")]
+    #[case("year = \"2024\"")]
+    fn test_parse_invalid(#[case] s: &str) {
+        assert!(Config::parse(s).is_err());
+    }
+
+    #[rstest]
+    fn test_load_missing_file() {
+        let config = Config::load(Path::new("/nonexistent/aoc.toml")).unwrap();
+
+        assert_eq!(config, Config::default());
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt::Display;
+
+use crate::Point;
+
+/// One of the four orthogonal directions, `Up` being towards row 0.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All the directions, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn delta(&self) -> Point<i64> {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// Parse an arrow (`^`, `v`, `<`, `>`) or a letter (`U`, `D`, `L`, `R`).
+impl TryFrom<char> for Direction {
+    type Error = InvalidDirection;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' => Ok(Direction::Up),
+            '>' | 'R' => Ok(Direction::Right),
+            'v' | 'D' => Ok(Direction::Down),
+            '<' | 'L' => Ok(Direction::Left),
+            _ => Err(InvalidDirection(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDirection(pub char);
+
+impl Display for InvalidDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid direction {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDirection {}
+
+/// One of the eight directions, including the diagonal ones.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All the directions, clockwise from `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turn 45° counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turn 45° clockwise.
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn delta(&self) -> Point<i64> {
+        match self {
+            Direction8::Up => Point::new(0, -1),
+            Direction8::UpRight => Point::new(1, -1),
+            Direction8::Right => Point::new(1, 0),
+            Direction8::DownRight => Point::new(1, 1),
+            Direction8::Down => Point::new(0, 1),
+            Direction8::DownLeft => Point::new(-1, 1),
+            Direction8::Left => Point::new(-1, 0),
+            Direction8::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|d| d == self).unwrap()
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Direction::Up, Direction::Left, Direction::Right, Direction::Down)]
+    #[case(Direction::Right, Direction::Up, Direction::Down, Direction::Left)]
+    #[case(Direction::Down, Direction::Right, Direction::Left, Direction::Up)]
+    #[case(Direction::Left, Direction::Down, Direction::Up, Direction::Right)]
+    fn test_turns(
+        #[case] d: Direction,
+        #[case] left: Direction,
+        #[case] right: Direction,
+        #[case] reverse: Direction,
+    ) {
+        assert_eq!(d.turn_left(), left);
+        assert_eq!(d.turn_right(), right);
+        assert_eq!(d.reverse(), reverse);
+    }
+
+    #[rstest]
+    fn test_delta_moves_point() {
+        let p = Point::<usize>::new(1, 1);
+
+        assert_eq!(
+            p.checked_offset(Direction::Up.delta()),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(
+            p.checked_offset(Direction::Right.delta()),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(
+            Point::<usize>::new(0, 0).checked_offset(Direction::Left.delta()),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case('^', Direction::Up)]
+    #[case('U', Direction::Up)]
+    #[case('>', Direction::Right)]
+    #[case('R', Direction::Right)]
+    #[case('v', Direction::Down)]
+    #[case('D', Direction::Down)]
+    #[case('<', Direction::Left)]
+    #[case('L', Direction::Left)]
+    fn test_parse(#[case] c: char, #[case] expected: Direction) {
+        assert_eq!(Direction::try_from(c), Ok(expected));
+    }
+
+    #[rstest]
+    fn test_parse_invalid() {
+        let err = Direction::try_from('x').unwrap_err();
+
+        assert_eq!(err.to_string(), "invalid direction 'x'");
+    }
+
+    #[rstest]
+    fn test_direction8_turns() {
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+    }
+
+    #[rstest]
+    fn test_direction8_deltas_are_distinct_neighbours() {
+        for d in Direction8::ALL {
+            let delta = d.delta();
+
+            assert_eq!(delta + d.reverse().delta(), Point::new(0, 0));
+            assert!(delta.x.abs() <= 1 && delta.y.abs() <= 1 && delta != Point::new(0, 0));
+        }
+    }
+
+    #[rstest]
+    fn test_direction8_from_direction() {
+        for d in Direction::ALL {
+            assert_eq!(Direction8::from(d).delta(), d.delta());
+        }
+    }
+}
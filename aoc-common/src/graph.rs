@@ -0,0 +1,432 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::{Grid, Point};
+
+/// A graph searched by [`bfs`], [`dijkstra`] and [`astar`].
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+
+    /// The nodes reachable from `node` in a single step, with the cost of that step.
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+/// A grid seen as a graph, each cell being connected to its four neighbours. `cost` is called
+/// with the current and next cells, and returns the cost of the step or `None` if it isn't
+/// allowed (e.g. a wall).
+///
+/// `Vec<Vec<T>>` grids can be converted with `Grid::from`.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    cost: F,
+}
+
+impl<'a, T, F> GridGraph<'a, T, F>
+where
+    F: Fn(&T, &T) -> Option<u64>,
+{
+    pub fn new(grid: &'a Grid<T>, cost: F) -> Self {
+        Self { grid, cost }
+    }
+}
+
+impl<T, F> Graph for GridGraph<'_, T, F>
+where
+    F: Fn(&T, &T) -> Option<u64>,
+{
+    type Node = Point<usize>;
+
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, u64)> {
+        self.grid
+            .neighbours4(node)
+            .filter_map(move |n| (self.cost)(&self.grid[node], &self.grid[n]).map(|c| (n, c)))
+    }
+}
+
+/// The outcome of a search: the distance from the start to every node reached, and for each of
+/// them all the nodes they can be reached from along a shortest path.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    start: N,
+    distances: HashMap<N, u64>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N> Search<N>
+where
+    N: Copy + Eq + Hash,
+{
+    fn new(start: N) -> Self {
+        Self {
+            start,
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: N) -> Option<u64> {
+        self.distances.get(&node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, u64> {
+        &self.distances
+    }
+
+    /// The nodes `node` can be reached from along a shortest path.
+    pub fn predecessors(&self, node: N) -> &[N] {
+        self.predecessors.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    /// A shortest path from the start to `target`, both included.
+    pub fn path(&self, target: N) -> Option<Vec<N>> {
+        self.distances.get(&target)?;
+
+        let mut path = vec![target];
+        let mut node = target;
+
+        while node != self.start {
+            node = self.predecessors(node)[0];
+            path.push(node);
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// All the shortest paths from the start to `target`. Zero-cost steps can make two nodes
+    /// predecessors of each other, so a path never goes through the same node twice.
+    pub fn all_paths(&self, target: N) -> Vec<Vec<N>> {
+        let mut paths = Vec::new();
+
+        if self.distances.contains_key(&target) {
+            self.collect_paths(&mut vec![target], &mut paths);
+        }
+
+        paths
+    }
+
+    /// Extend `path`, which goes backwards from the target, to the start along every predecessor.
+    fn collect_paths(&self, path: &mut Vec<N>, paths: &mut Vec<Vec<N>>) {
+        let node = path[path.len() - 1];
+
+        if node == self.start {
+            paths.push(path.iter().rev().copied().collect());
+            return;
+        }
+
+        for &p in self.predecessors(node) {
+            if !path.contains(&p) {
+                path.push(p);
+                self.collect_paths(path, paths);
+                path.pop();
+            }
+        }
+    }
+
+    /// All the nodes lying on at least one shortest path from the start to `target`.
+    pub fn nodes_on_paths(&self, target: N) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut todo = Vec::new();
+
+        if self.distances.contains_key(&target) {
+            todo.push(target);
+        }
+
+        while let Some(node) = todo.pop() {
+            if nodes.insert(node) {
+                todo.extend_from_slice(self.predecessors(node));
+            }
+        }
+
+        nodes
+    }
+
+    /// Record reaching `next` from `node` at distance `dist`. Returns true if it's the shortest
+    /// distance found so far for `next`.
+    fn visit(&mut self, node: N, next: N, dist: u64) -> bool {
+        match self.distances.get(&next) {
+            Some(&d) if dist > d => false,
+            Some(&d) if dist == d => {
+                self.predecessors.entry(next).or_default().push(node);
+                false
+            }
+            _ => {
+                self.distances.insert(next, dist);
+                self.predecessors.insert(next, vec![node]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth first search from `start`, every step counting as 1 whatever its cost.
+pub fn bfs<G: Graph>(graph: &G, start: G::Node) -> Search<G::Node> {
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let dist = search.distances[&node] + 1;
+
+        for (next, _) in graph.neighbours(node) {
+            if search.visit(node, next, dist) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Shortest paths from `start` to every reachable node.
+pub fn dijkstra<G: Graph>(graph: &G, start: G::Node) -> Search<G::Node> {
+    let mut search = Search::new(start);
+    let mut queue = BinaryHeap::from([State::new(start, 0, 0)]);
+
+    while let Some(State { node, cost, .. }) = queue.pop() {
+        if cost > search.distances[&node] {
+            continue;
+        }
+
+        for (next, c) in graph.neighbours(node) {
+            if search.visit(node, next, cost + c) {
+                queue.push(State::new(next, cost + c, cost + c));
+            }
+        }
+    }
+
+    search
+}
+
+/// Shortest path from `start` to the first node matching `is_goal`, guided by `heuristic`. The
+/// heuristic must never overestimate the remaining cost, and must be consistent for the
+/// predecessors of the search to include all the shortest paths.
+///
+/// The search goes on until every node as close as the goal has been expanded, so that all the
+/// shortest paths to it are found. Returns the goal reached, or `None` if no goal is reachable.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(G::Node) -> bool,
+    heuristic: impl Fn(G::Node) -> u64,
+) -> Option<(G::Node, Search<G::Node>)> {
+    let mut search = Search::new(start);
+    let mut queue = BinaryHeap::from([State::new(start, 0, heuristic(start))]);
+    let mut goal = None;
+
+    while let Some(State {
+        node,
+        cost,
+        priority,
+    }) = queue.pop()
+    {
+        // Nodes as close as the goal can still lead to it along another shortest path.
+        if goal.is_some_and(|(_, dist)| priority > dist) {
+            break;
+        }
+
+        if cost > search.distances[&node] {
+            continue;
+        }
+
+        if is_goal(node) {
+            goal.get_or_insert((node, cost));
+            continue;
+        }
+
+        for (next, c) in graph.neighbours(node) {
+            if search.visit(node, next, cost + c) {
+                queue.push(State::new(next, cost + c, cost + c + heuristic(next)));
+            }
+        }
+    }
+
+    goal.map(|(node, _)| (node, search))
+}
+
+/// An entry of the priority queue, ordered so that the lowest priority is popped first.
+struct State<N> {
+    node: N,
+    cost: u64,
+    priority: u64,
+}
+
+impl<N> State<N> {
+    fn new(node: N, cost: u64, priority: u64) -> Self {
+        Self {
+            node,
+            cost,
+            priority,
+        }
+    }
+}
+
+impl<N> PartialEq for State<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for State<N> {}
+
+impl<N> PartialOrd for State<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for State<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::parse_test_input;
+
+    #[fixture]
+    fn maze() -> Grid<char> {
+        let input = parse_test_input(
+            "
+            S..#
+            .#..
+            ...E
+            ",
+        );
+
+        Grid::parse(input.lines(), |c| c)
+    }
+
+    fn open(_: &char, next: &char) -> Option<u64> {
+        (*next != '#').then_some(1)
+    }
+
+    #[rstest]
+    fn test_bfs(maze: Grid<char>) {
+        let graph = GridGraph::new(&maze, open);
+        let search = bfs(&graph, Point::new(0, 0));
+
+        assert_eq!(search.distance(Point::new(3, 2)), Some(5));
+        assert_eq!(search.distance(Point::new(3, 0)), None);
+        assert_eq!(search.path(Point::new(3, 2)).unwrap().len(), 6);
+    }
+
+    #[rstest]
+    fn test_all_paths(maze: Grid<char>) {
+        let graph = GridGraph::new(&maze, open);
+        let search = bfs(&graph, Point::new(0, 0));
+
+        let paths = search.all_paths(Point::new(3, 2));
+
+        assert_eq!(paths.len(), 3);
+        assert!(paths.iter().all(|p| p.len() == 6));
+        assert_eq!(search.nodes_on_paths(Point::new(3, 2)).len(), 10);
+    }
+
+    #[rstest]
+    fn test_dijkstra_uses_costs(maze: Grid<char>) {
+        // Leaving the start is expensive, whichever way
+        let graph = GridGraph::new(&maze, |cur, next| match (cur, next) {
+            (_, '#') => None,
+            ('S', _) => Some(10),
+            _ => Some(1),
+        });
+
+        let search = dijkstra(&graph, Point::new(0, 0));
+
+        assert_eq!(search.distance(Point::new(3, 2)), Some(14));
+        assert_eq!(search.distance(Point::new(0, 1)), Some(10));
+    }
+
+    #[rstest]
+    fn test_dijkstra_path(maze: Grid<char>) {
+        let graph = GridGraph::new(&maze, open);
+        let search = dijkstra(&graph, Point::new(0, 0));
+
+        let path = search.path(Point::new(2, 0)).unwrap();
+
+        assert_eq!(
+            path,
+            vec![Point::new(0, 0), Point::new(1, 0), Point::new(2, 0)]
+        );
+        assert_eq!(search.predecessors(Point::new(2, 0)), &[Point::new(1, 0)]);
+    }
+
+    #[rstest]
+    fn test_astar(maze: Grid<char>) {
+        let graph = GridGraph::new(&maze, open);
+        let goal = Point::new(3, 2);
+
+        let (reached, search) = astar(
+            &graph,
+            Point::new(0, 0),
+            |p| p == goal,
+            |p| p.manhattan(&goal) as u64,
+        )
+        .unwrap();
+
+        assert_eq!(reached, goal);
+        assert_eq!(search.distance(goal), Some(5));
+        assert_eq!(search.path(goal).unwrap().first(), Some(&Point::new(0, 0)));
+    }
+
+    #[rstest]
+    fn test_astar_all_paths() {
+        let grid = Grid::from(vec![vec!['.'; 5]; 5]);
+        let graph = GridGraph::new(&grid, open);
+        let goal = Point::new(4, 4);
+
+        let (_, search) = astar(
+            &graph,
+            Point::new(0, 0),
+            |p| p == goal,
+            |p| p.manhattan(&goal) as u64,
+        )
+        .unwrap();
+        let paths = search.all_paths(goal);
+        let expected = dijkstra(&graph, Point::new(0, 0)).all_paths(goal);
+
+        assert_eq!(paths.len(), 70);
+        assert_eq!(
+            paths.into_iter().collect::<HashSet<_>>(),
+            expected.into_iter().collect::<HashSet<_>>()
+        );
+        assert_eq!(search.nodes_on_paths(goal).len(), 25);
+    }
+
+    #[rstest]
+    fn test_all_paths_zero_cost() {
+        // Moving between a and b is free, both ways
+        let grid = Grid::from(vec![vec!['S', 'a', 'b', 'E']]);
+        let graph = GridGraph::new(&grid, |cur, next| match (cur, next) {
+            ('a', 'b') | ('b', 'a') => Some(0),
+            _ => Some(1),
+        });
+        let search = dijkstra(&graph, Point::new(0, 0));
+        let goal = Point::new(3, 0);
+
+        assert_eq!(search.distance(goal), Some(2));
+        assert_eq!(
+            search.all_paths(goal),
+            vec![vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                goal
+            ]]
+        );
+    }
+
+    #[rstest]
+    fn test_astar_unreachable(maze: Grid<char>) {
+        let graph = GridGraph::new(&maze, open);
+
+        let res = astar(&graph, Point::new(0, 0), |p| p == Point::new(3, 0), |_| 0);
+
+        assert!(res.is_none());
+    }
+}
@@ -0,0 +1,576 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::{Line, ParseError, Point};
+
+/// A rectangular 2D grid, stored row by row. Positions use `x` for the column and `y` for the
+/// row, `(0, 0)` being the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Build a grid from a puzzle's input lines, converting every character with `f`.
+    ///
+    /// Panics if the lines don't all have the same length.
+    pub fn parse<'a>(
+        input: impl IntoIterator<Item = &'a str>,
+        mut f: impl FnMut(char) -> T,
+    ) -> Self {
+        let mut input = input.into_iter().peekable();
+        let width = input.peek().map_or(0, |l| l.chars().count());
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (y, line) in input.enumerate() {
+            height += 1;
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+
+            assert_eq!(
+                cells.len() - len,
+                width,
+                "line {} has a different width than the first one",
+                y + 1
+            );
+        }
+
+        Self::new(width, height, cells)
+    }
+
+    /// Build a grid from a puzzle's input lines, converting every character with `f`, which
+    /// returns `None` for the characters that aren't `expected`.
+    pub fn try_parse<'a>(
+        input: impl IntoIterator<Item = Line<'a>>,
+        mut f: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut input = input.into_iter().peekable();
+        let width = input.peek().map_or(0, |l| l.text().chars().count());
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in input {
+            let mut len = 0;
+
+            for (i, c) in line.text().char_indices() {
+                if len == width {
+                    let expected = format!("the end of the line, after {} characters", width);
+                    return Err(line.error(&line.text()[i..], expected));
+                }
+
+                cells.push(f(c).ok_or_else(|| line.error(&line.text()[i..], expected))?);
+                len += 1;
+            }
+
+            if len < width {
+                return Err(line.error_at_end(expected));
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point<usize>) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point<usize>) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point<usize>) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    /// Create a new grid of the same size by converting every cell with `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// All the positions of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All the cells of the grid with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// All the diagonals going down and to the right, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.non_empty_size();
+        let starts = (0..height)
+            .rev()
+            .map(|y| Point::new(0, y))
+            .chain((1..width).map(|x| Point::new(x, 0)));
+
+        starts.map(move |start| self.walk(start, |p| Some(Point::new(p.x + 1, p.y + 1))))
+    }
+
+    /// All the diagonals going down and to the left, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.non_empty_size();
+        let starts = (0..width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..height).map(move |y| Point::new(width - 1, y)));
+
+        starts.map(move |start| {
+            self.walk(start, |p| {
+                p.x.checked_sub(1).map(|x| Point::new(x, p.y + 1))
+            })
+        })
+    }
+
+    /// The size of the grid, or `(0, 0)` if it has no cells, so that an empty grid has no
+    /// diagonals.
+    fn non_empty_size(&self) -> (usize, usize) {
+        if self.cells.is_empty() {
+            (0, 0)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    fn walk(
+        &self,
+        start: Point<usize>,
+        next: impl Fn(Point<usize>) -> Option<Point<usize>>,
+    ) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |&p| next(p)).map_while(move |p| self.get(p))
+    }
+
+    /// The neighbours of `p` above, below, to the left and to the right of it, within the grid.
+    pub fn neighbours4(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        const OFFSETS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+        self.offsets(p, &OFFSETS)
+    }
+
+    /// The neighbours of `p`, including the diagonal ones, within the grid.
+    pub fn neighbours8(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ];
+
+        self.offsets(p, &OFFSETS)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        p: Point<usize>,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point<usize>> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = p.x.checked_add_signed(dx)?;
+            let y = p.y.checked_add_signed(dy)?;
+            let n = Point::new(x, y);
+
+            self.contains(n).then_some(n)
+        })
+    }
+
+    /// The position of the first cell, row by row, matching `pred`.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point<usize>> {
+        self.iter().find(|(_, v)| pred(v)).map(|(p, _)| p)
+    }
+
+    /// The positions of all the cells equal to `value`.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point<usize>> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(p, _)| p)
+    }
+}
+
+/// Convert a grid stored as a list of rows. Panics if the rows don't all have the same length.
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "all the rows must have the same width"
+        );
+
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<usize>) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", p.x, p.y))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<usize>) -> &mut Self::Output {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", p.x, p.y))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A rectangular grid of bytes borrowed from a puzzle input, such as a map of ASCII characters,
+/// indexed without copying it. See [`PuzzleInput::grid`](crate::PuzzleInput::grid).
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+    /// Distance between the starts of two rows, which are followed by line endings.
+    stride: usize,
+}
+
+impl<'a> GridView<'a> {
+    pub(crate) fn new(bytes: &'a [u8], width: usize, height: usize, stride: usize) -> Self {
+        assert!(
+            height == 0 || bytes.len() >= (height - 1) * stride + width,
+            "a {}x{} grid doesn't fit in {} bytes",
+            width,
+            height,
+            bytes.len()
+        );
+
+        Self {
+            bytes,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point<usize>) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point<usize>) -> Option<u8> {
+        self.contains(p)
+            .then(|| self.bytes[p.y * self.stride + p.x])
+    }
+
+    pub fn row(&self, y: usize) -> &'a [u8] {
+        assert!(y < self.height, "row {} out of bounds", y);
+
+        &self.bytes[y * self.stride..y * self.stride + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// All the positions of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All the cells of the grid with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, u8)> + '_ {
+        self.points().map(|p| (p, self[p]))
+    }
+
+    /// The position of the first cell, row by row, matching `pred`.
+    pub fn find(&self, mut pred: impl FnMut(u8) -> bool) -> Option<Point<usize>> {
+        self.iter().find(|&(_, b)| pred(b)).map(|(p, _)| p)
+    }
+
+    /// The positions of all the cells equal to `value`.
+    pub fn positions_of(&self, value: u8) -> impl Iterator<Item = Point<usize>> + '_ {
+        self.iter()
+            .filter(move |&(_, b)| b == value)
+            .map(|(p, _)| p)
+    }
+
+    /// Copy the grid into an owned one, e.g. to modify it, converting every cell with `f`.
+    pub fn to_grid<T>(&self, f: impl FnMut(u8) -> T) -> Grid<T> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.rows().flatten().copied().map(f).collect(),
+        )
+    }
+}
+
+impl Index<Point<usize>> for GridView<'_> {
+    type Output = u8;
+
+    fn index(&self, p: Point<usize>) -> &Self::Output {
+        assert!(
+            self.contains(p),
+            "({}, {}) is outside of the grid",
+            p.x,
+            p.y
+        );
+
+        &self.bytes[p.y * self.stride + p.x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{lines, parse_test_input};
+
+    #[fixture]
+    fn grid() -> Grid<char> {
+        let input = parse_test_input(
+            "
+            abc
+            def
+            ",
+        );
+
+        Grid::parse(input.lines(), |c| c)
+    }
+
+    #[rstest]
+    fn test_parse(grid: Grid<char>) {
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(2, 0)], 'c');
+        assert_eq!(grid[Point::new(0, 1)], 'd');
+    }
+
+    #[rstest]
+    #[should_panic(expected = "line 2 has a different width")]
+    fn test_parse_ragged_input() {
+        Grid::parse(parse_test_input("abc\nde").lines(), |c| c);
+    }
+
+    #[rstest]
+    fn test_try_parse() {
+        let digit = |c: char| c.to_digit(10);
+
+        let grid = Grid::try_parse(lines(&parse_test_input("12\n34")), digit, "a digit").unwrap();
+        assert_eq!(grid[Point::new(1, 1)], 4);
+
+        let err =
+            Grid::try_parse(lines(&parse_test_input("12\n3x")), digit, "a digit").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = Grid::try_parse(lines(&parse_test_input("12\n3")), digit, "a digit").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err =
+            Grid::try_parse(lines(&parse_test_input("12\n345")), digit, "a digit").unwrap_err();
+        assert_eq!(err.column, 3);
+        assert!(err.expected.contains("end of the line"));
+    }
+
+    #[rstest]
+    fn test_from_rows(grid: Grid<char>) {
+        let rows = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+
+        assert_eq!(Grid::from(rows), grid);
+    }
+
+    #[rstest]
+    fn test_get(grid: Grid<char>) {
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&'e'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+    }
+
+    #[rstest]
+    fn test_index_mut(mut grid: Grid<char>) {
+        grid[Point::new(1, 0)] = 'X';
+
+        assert_eq!(grid.to_string(), "aXc\ndef\n");
+    }
+
+    #[rstest]
+    fn test_rows_and_columns(grid: Grid<char>) {
+        let rows = grid
+            .rows()
+            .map(|r| r.iter().collect::<String>())
+            .collect_vec();
+        let columns = grid.columns().map(|c| c.collect::<String>()).collect_vec();
+
+        assert_eq!(rows, vec!["abc", "def"]);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[rstest]
+    fn test_diagonals(grid: Grid<char>) {
+        let diagonals = grid
+            .diagonals()
+            .map(|d| d.collect::<String>())
+            .collect_vec();
+        let anti_diagonals = grid
+            .anti_diagonals()
+            .map(|d| d.collect::<String>())
+            .collect_vec();
+
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+        assert_eq!(anti_diagonals, vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[rstest]
+    #[case::no_columns(0, 3)]
+    #[case::no_rows(3, 0)]
+    fn test_diagonals_empty(#[case] width: usize, #[case] height: usize) {
+        let grid = Grid::<char>::new(width, height, Vec::new());
+
+        assert_eq!(grid.diagonals().count(), 0);
+        assert_eq!(grid.anti_diagonals().count(), 0);
+    }
+
+    #[rstest]
+    fn test_neighbours4(grid: Grid<char>) {
+        let n = grid.neighbours4(Point::new(0, 0)).collect_vec();
+
+        assert_eq!(n, vec![Point::new(1, 0), Point::new(0, 1)]);
+    }
+
+    #[rstest]
+    fn test_neighbours8(grid: Grid<char>) {
+        let n = grid.neighbours8(Point::new(1, 1)).collect_vec();
+
+        assert_eq!(
+            n,
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(0, 1),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_find_and_positions_of() {
+        let grid = Grid::parse(parse_test_input("a.b\n.a.").lines(), |c| c);
+
+        assert_eq!(grid.find(|&c| c == 'b'), Some(Point::new(2, 0)));
+        assert_eq!(grid.find(|&c| c == 'z'), None);
+        assert_eq!(
+            grid.positions_of(&'a').collect_vec(),
+            vec![Point::new(0, 0), Point::new(1, 1)]
+        );
+    }
+
+    #[rstest]
+    fn test_map(grid: Grid<char>) {
+        let upper = grid.map(|c| c.to_ascii_uppercase());
+
+        assert_eq!(upper.to_string(), "ABC\nDEF\n");
+    }
+
+    #[rstest]
+    fn test_view() {
+        let view = GridView::new(b"ab\ncd\nef", 2, 3, 3);
+
+        assert_eq!((view.width(), view.height()), (2, 3));
+        assert_eq!(view[Point::new(1, 1)], b'd');
+        assert_eq!(view.get(Point::new(2, 0)), None);
+        assert_eq!(view.rows().collect_vec(), vec![b"ab", b"cd", b"ef"]);
+        assert_eq!(view.find(|b| b == b'e'), Some(Point::new(0, 2)));
+        assert_eq!(view.positions_of(b'z').count(), 0);
+        assert_eq!(
+            view.to_grid(char::from),
+            Grid::parse(["ab", "cd", "ef"], |c| c)
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::io::Read;
+use std::ops::{Add, Index, Mul, Range, Sub};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs, io};
+
+use crate::config::{config, layered_path, workspace_root};
+use crate::{GridView, Line, ParseError};
+
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file doesn't exist.
+    NotFound { path: PathBuf },
+    /// The input file exists but couldn't be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The input file isn't valid UTF-8. `line` is the first line containing invalid data.
+    Utf8 { path: PathBuf, line: usize },
+    /// A line of the input couldn't be parsed.
+    Parse(ParseError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { path } => write!(
+                f,
+                "{} missing\nhint: download it with `just get <day>` or copy your puzzle input there",
+                path.display()
+            ),
+            InputError::Io { path, source } => {
+                write!(f, "unable to read {}: {}", path.display(), source)
+            }
+            InputError::Utf8 { path, line } => {
+                write!(f, "{}:{}: invalid UTF-8", path.display(), line)
+            }
+            InputError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A puzzle input, read once into a single buffer. Lines, rows of bytes and grids are handed out
+/// as views into it rather than copied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleInput {
+    text: String,
+    /// Range of each line in `text`, without its line ending.
+    lines: Vec<Range<usize>>,
+}
+
+impl PuzzleInput {
+    /// Split `text` into lines. Windows line endings are converted first.
+    pub fn new(mut text: String) -> Self {
+        if text.contains('\r') {
+            text = text.replace("\r\n", "\n");
+        }
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for (i, _) in text.match_indices('\n') {
+            lines.push(start..i);
+            start = i + 1;
+        }
+        if start < text.len() {
+            lines.push(start..text.len());
+        }
+
+        Self { text, lines }
+    }
+
+    /// The whole input, without its final line ending.
+    pub fn as_str(&self) -> &str {
+        &self.text[..self.lines.last().map_or(0, |l| l.end)]
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn line(&self, idx: usize) -> &str {
+        &self.text[self.lines[idx].clone()]
+    }
+
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator + Clone {
+        self.lines.iter().map(|l| &self.text[l.clone()])
+    }
+
+    /// The lines as bytes, e.g. to index ASCII maps directly.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[u8]> + ExactSizeIterator + Clone {
+        self.lines().map(str::as_bytes)
+    }
+
+    /// The whole input as a grid of bytes, which must be rectangular.
+    pub fn grid(&self) -> Result<GridView<'_>, ParseError> {
+        let width = self.lines.first().map_or(0, |l| l.len());
+
+        for (idx, range) in self.lines.iter().enumerate() {
+            let line = Line::new(idx, &self.text[range.clone()]);
+
+            match line.text().get(width..) {
+                Some("") => {}
+                Some(extra) => {
+                    let expected = format!("the end of the line, after {} characters", width);
+                    return Err(line.error(extra, expected));
+                }
+                None => {
+                    let expected = format!("{} characters, like the first line", width);
+                    return Err(line.error_at_end(expected));
+                }
+            }
+        }
+
+        // Lines are separated by a single `\n`, so rows are `width + 1` bytes apart.
+        Ok(GridView::new(
+            self.text.as_bytes(),
+            width,
+            self.lines.len(),
+            width + 1,
+        ))
+    }
+}
+
+impl From<String> for PuzzleInput {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for PuzzleInput {
+    fn from(text: &str) -> Self {
+        Self::new(text.to_owned())
+    }
+}
+
+impl Index<usize> for PuzzleInput {
+    type Output = str;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        self.line(idx)
+    }
+}
+
+/// Where a puzzle's input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolve the location of a puzzle input. An explicit path takes precedence, `-` meaning
+    /// stdin. Otherwise, `filename` is looked up in the input directory (see [`input_dir`]).
+    pub fn resolve(filename: &str, explicit: Option<&Path>) -> Self {
+        match explicit {
+            Some(p) if p == Path::new("-") => InputSource::Stdin,
+            Some(p) => InputSource::File(p.to_owned()),
+            None => InputSource::File(input_path(filename)),
+        }
+    }
+
+    pub fn read_input(&self) -> Result<PuzzleInput, InputError> {
+        Ok(PuzzleInput::new(self.read()?))
+    }
+
+    pub fn read_to_string(&self) -> Result<String, InputError> {
+        Ok(self.read()?.trim().to_owned())
+    }
+
+    fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => read_input(path),
+            InputSource::Stdin => {
+                let path = Path::new("<stdin>");
+                let mut bytes = Vec::new();
+
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|e| InputError::Io {
+                        path: path.to_owned(),
+                        source: e,
+                    })?;
+
+                decode(path, bytes)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Directory containing the puzzle inputs: `$AOC_INPUT_DIR` if set, otherwise the one set in the
+/// configuration, otherwise the `input` directory at the root of the workspace.
+pub fn input_dir() -> PathBuf {
+    input_dir_from(env::var_os(INPUT_DIR_VAR), config().input_dir.as_deref())
+}
+
+fn input_dir_from(var: Option<OsString>, configured: Option<&Path>) -> PathBuf {
+    layered_path(var, configured, || workspace_root().join("input"))
+}
+
+/// Path of a puzzle input file in the input directory.
+pub fn input_path(filename: &str) -> PathBuf {
+    input_dir().join(filename)
+}
+
+/// Name of the input file of `day` of `year`, relative to the input directory, e.g.
+/// `2024/day07.txt`.
+pub fn input_file(year: u16, day: u8) -> String {
+    format!("{}/day{:02}.txt", year, day)
+}
+
+pub fn try_get_input(filename: &str) -> Result<PuzzleInput, InputError> {
+    InputSource::resolve(filename, None).read_input()
+}
+
+pub fn try_get_input_as_string(filename: &str) -> Result<String, InputError> {
+    InputSource::resolve(filename, None).read_to_string()
+}
+
+pub fn try_get_input_as_int<T>(filename: &str) -> Result<Vec<T>, InputError>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Ord + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    parse_lines(&try_get_input(filename)?)
+}
+
+pub fn get_input(filename: &str) -> PuzzleInput {
+    try_get_input(filename).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn get_input_as_string(filename: &str) -> String {
+    try_get_input_as_string(filename).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn get_input_as_int<T>(filename: &str) -> Vec<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Ord + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    try_get_input_as_int(filename).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn read_input(path: &Path) -> Result<String, InputError> {
+    let bytes = fs::read(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound {
+            path: path.to_owned(),
+        },
+        _ => InputError::Io {
+            path: path.to_owned(),
+            source: e,
+        },
+    })?;
+
+    decode(path, bytes)
+}
+
+fn decode(path: &Path, bytes: Vec<u8>) -> Result<String, InputError> {
+    String::from_utf8(bytes).map_err(|e| {
+        let valid = &e.as_bytes()[..e.utf8_error().valid_up_to()];
+
+        InputError::Utf8 {
+            path: path.to_owned(),
+            line: valid.iter().filter(|&&b| b == b'\n').count() + 1,
+        }
+    })
+}
+
+fn parse_lines<T>(input: &PuzzleInput) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, l)| {
+            l.parse().map_err(|e: <T as FromStr>::Err| {
+                InputError::Parse(ParseError::new(idx, l, 0, format!("a number ({})", e)))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_missing_input() {
+        let err = try_get_input("day00-missing.txt").unwrap_err();
+
+        assert!(
+            matches!(&err, InputError::NotFound { path } if path.ends_with("input/day00-missing.txt"))
+        );
+        assert!(err.to_string().contains("day00-missing.txt missing"));
+    }
+
+    #[rstest]
+    fn test_input_file() {
+        assert_eq!(input_file(2024, 7), "2024/day07.txt");
+    }
+
+    #[rstest]
+    fn test_resolve_explicit_path() {
+        let src = InputSource::resolve("day01.txt", Some(Path::new("/tmp/example.txt")));
+
+        assert_eq!(src, InputSource::File(PathBuf::from("/tmp/example.txt")));
+    }
+
+    #[rstest]
+    fn test_resolve_stdin() {
+        let src = InputSource::resolve("day01.txt", Some(Path::new("-")));
+
+        assert_eq!(src, InputSource::Stdin);
+    }
+
+    #[rstest]
+    fn test_resolve_default() {
+        let src = InputSource::resolve("day01.txt", None);
+
+        assert_eq!(src, InputSource::File(input_path("day01.txt")));
+    }
+
+    #[rstest]
+    fn test_input_dir_from_env() {
+        let dir = input_dir_from(
+            Some(OsString::from("/srv/aoc")),
+            Some(Path::new("/etc/aoc")),
+        );
+
+        assert_eq!(dir, PathBuf::from("/srv/aoc"));
+    }
+
+    #[rstest]
+    fn test_input_dir_from_config() {
+        let dir = input_dir_from(None, Some(Path::new("/etc/aoc")));
+
+        assert_eq!(dir, PathBuf::from("/etc/aoc"));
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(OsString::new()))]
+    fn test_input_dir_default(#[case] var: Option<OsString>) {
+        let dir = input_dir_from(var, None);
+
+        assert!(dir.ends_with("input"));
+        assert!(dir.parent().unwrap().join("Cargo.toml").exists());
+    }
+
+    #[rstest]
+    fn test_decode_invalid_utf8_reports_line() {
+        let bytes = b"abc\ndef\ngh\xffi\n".to_vec();
+
+        let err = decode(Path::new("input/day01.txt"), bytes).unwrap_err();
+
+        assert_eq!(err.to_string(), "input/day01.txt:3: invalid UTF-8");
+    }
+
+    #[rstest]
+    fn test_parse_lines() {
+        let input = PuzzleInput::from("1\n-2\n3");
+
+        assert_eq!(parse_lines::<i32>(&input).unwrap(), vec![1, -2, 3]);
+    }
+
+    #[rstest]
+    fn test_parse_lines_reports_offending_line() {
+        let input = PuzzleInput::from("1\n2x");
+
+        let err = parse_lines::<i32>(&input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a number (invalid digit found in string)\n  2 | 2x\n    | ^"
+        );
+    }
+
+    #[rstest]
+    #[case("", vec![])]
+    #[case("a\n", vec!["a"])]
+    #[case("a\n\nbc", vec!["a", "", "bc"])]
+    #[case("a\r\nbc\r\n", vec!["a", "bc"])]
+    fn test_puzzle_input_lines(#[case] text: &str, #[case] expected: Vec<&str>) {
+        let input = PuzzleInput::from(text);
+
+        assert_eq!(input.lines().collect::<Vec<_>>(), expected);
+        assert_eq!(input.len(), expected.len());
+        assert_eq!(input.as_str(), expected.join("\n"));
+    }
+
+    #[rstest]
+    fn test_puzzle_input_index() {
+        let input = PuzzleInput::from("ab\ncd\n");
+
+        assert_eq!(&input[1], "cd");
+        assert_eq!(input.rows().next(), Some(&b"ab"[..]));
+    }
+
+    #[rstest]
+    fn test_puzzle_input_grid() {
+        let input = PuzzleInput::from("ab\ncd\n");
+        let grid = input.grid().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.row(1), b"cd");
+    }
+
+    #[rstest]
+    #[case("abc\nde", 2, 3)]
+    #[case("ab\ncde", 2, 3)]
+    fn test_puzzle_input_grid_ragged(
+        #[case] text: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+    ) {
+        let err = PuzzleInput::from(text).grid().unwrap_err();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+}
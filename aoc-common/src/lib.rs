@@ -1,60 +1,102 @@
-use std::fmt::Debug;
-use std::fs::{read_to_string, File};
-use std::io::{BufRead, BufReader};
-use std::ops::{Add, Mul, Sub};
-use std::str::FromStr;
+use std::io;
 
-use itertools::Itertools;
 use textwrap::dedent;
 use tracing_subscriber::filter::LevelFilter;
 use tracing_subscriber::fmt::format::FmtSpan;
-use tracing_subscriber::EnvFilter;
-
-pub fn get_input(filename: &str) -> Vec<String> {
-    let path = format!("{}/../input/{}", env!("CARGO_MANIFEST_DIR"), filename);
-    let file = match File::open(path) {
-        Ok(file) => file,
-        Err(error) => panic!("Unable to open file {}: {}", filename, error),
-    };
-
-    let reader = BufReader::new(file);
-
-    reader.lines().map(|l| l.unwrap()).collect()
-}
-
-pub fn get_input_as_string(filename: &str) -> String {
-    let path = format!("{}/../input/{}", env!("CARGO_MANIFEST_DIR"), filename);
-    let reader = match read_to_string(path) {
-        Ok(r) => r,
-        Err(error) => panic!("Unable to open file {}: {}", filename, error),
-    };
-
-    reader.trim().parse().unwrap()
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+mod alloc;
+mod answer;
+mod config;
+mod direction;
+pub mod graph;
+mod grid;
+mod input;
+mod params;
+mod parse;
+mod point;
+mod solution;
+mod trace;
+
+pub use alloc::{measure, AllocStats, CountingAllocator};
+
+pub use answer::Answer;
+pub use config::{cache_dir, config, BenchConfig, Config, CACHE_DIR_VAR, CONFIG_VAR, DEFAULT_YEAR};
+pub use direction::{Direction, Direction8, InvalidDirection};
+pub use grid::{Grid, GridView};
+pub use input::{
+    get_input,
+    get_input_as_int,
+    get_input_as_string,
+    input_dir,
+    input_file,
+    input_path,
+    try_get_input,
+    try_get_input_as_int,
+    try_get_input_as_string,
+    InputError,
+    InputSource,
+    PuzzleInput,
+    INPUT_DIR_VAR,
+};
+pub use params::{Param, Params};
+pub use parse::{blocks, columns, key_values, lines, Line, ParseError};
+pub use point::Point;
+pub use solution::Solution;
+pub use trace::{Trace, TraceFormat, TraceGuard};
+
+/// Set up tracing to stdout. Spans are filtered with `filter` (in the syntax of `RUST_LOG`) if
+/// given, otherwise with `$RUST_LOG`, otherwise with the `log` setting of the configuration,
+/// otherwise at the `info` level.
+///
+/// With a `trace`, the spans are also written to its file. The returned guard must be kept until
+/// the end of the program for the file to be complete.
+pub fn tracing_init(filter: Option<&str>, trace: Option<&Trace>) -> io::Result<TraceGuard> {
+    tracing_init_with_writer(std::io::stdout, filter, trace)
 }
 
-pub fn get_input_as_int<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Ord + FromStr>(
-    filename: &str,
-) -> Vec<T>
+/// Same as [`tracing_init`], but the traces are written to `writer` instead of stdout.
+pub fn tracing_init_with_writer<W>(
+    writer: W,
+    filter: Option<&str>,
+    trace: Option<&Trace>,
+) -> io::Result<TraceGuard>
 where
-    <T as FromStr>::Err: Debug,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
 {
-    get_input(filename)
-        .iter()
-        .map(|i| i.parse().unwrap())
-        .collect()
-}
-
-pub fn tracing_init() {
+    let directives = filter
+        .map(str::to_owned)
+        .or_else(|| std::env::var(EnvFilter::DEFAULT_ENV).ok())
+        .filter(|f| !f.is_empty())
+        .or_else(|| config().log.clone())
+        .unwrap_or_default();
     let env_filter = EnvFilter::builder()
         .with_default_directive(LevelFilter::INFO.into())
-        .from_env_lossy();
+        .parse_lossy(directives);
+
+    let (trace_layer, guard) = match trace {
+        Some(trace) => {
+            let (layer, guard) = trace::trace_layer(trace)?;
+            (Some(layer), guard)
+        }
+        None => (None, TraceGuard::none()),
+    };
 
-    tracing_subscriber::fmt()
-        .with_writer(std::io::stdout)
-        .with_span_events(FmtSpan::CLOSE)
-        .with_env_filter(env_filter)
-        .compact()
+    tracing_subscriber::registry()
+        .with(trace_layer)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_span_events(FmtSpan::CLOSE)
+                .compact()
+                .with_filter(env_filter),
+        )
         .init();
+
+    Ok(guard)
 }
 
 pub fn format_duration(nanos: u128) -> String {
@@ -67,15 +109,28 @@ pub fn format_duration(nanos: u128) -> String {
     }
 }
 
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.03}{}", size, UNITS[unit])
+}
+
 /// Parse a puzzle's input data provided as a multi line string. The input is dedented first, then
 /// the first and last lines are removed if they are empty.
 /// This is useful for providing test input as a string.
-pub fn parse_test_input(input: &str) -> Vec<String> {
-    dedent(input)
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect_vec()
+pub fn parse_test_input(input: &str) -> PuzzleInput {
+    PuzzleInput::from(dedent(input).trim())
 }
 
 /// Parse a puzzle's input data provided as a multi line string. The input is dedented first, then
@@ -85,26 +140,9 @@ pub fn parse_test_input_as_string(input: &str) -> String {
     dedent(input).trim().to_owned()
 }
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-pub struct Point<T>
-where
-    T: Clone + Copy,
-{
-    pub x: T,
-    pub y: T,
-}
-
-impl<T> Point<T>
-where
-    T: Clone + Copy,
-{
-    pub fn new(x: T, y: T) -> Self {
-        Self { x, y }
-    }
-}
-
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
     use rstest::rstest;
 
     use super::*;
@@ -116,7 +154,7 @@ mod tests {
 foobar";
 
         let expected = vec!["abc", "123", "foobar"];
-        assert_eq!(expected, parse_test_input(input));
+        assert_eq!(expected, parse_test_input(input).lines().collect_vec());
     }
     #[rstest]
     fn test_parse_input_dedents_input() {
@@ -128,7 +166,7 @@ foobar";
 
         let expected = vec!["abc", "123", "foobar"];
 
-        assert_eq!(expected, parse_test_input(input));
+        assert_eq!(expected, parse_test_input(input).lines().collect_vec());
     }
 
     #[rstest]
@@ -143,7 +181,7 @@ foobar";
 
         let expected = vec!["abc", "123", "", "foobar"];
 
-        assert_eq!(expected, parse_test_input(input));
+        assert_eq!(expected, parse_test_input(input).lines().collect_vec());
     }
 
     #[rstest]
@@ -192,4 +230,15 @@ foobar";
     fn test_format_duration(#[case] nanos: u128, #[case] expected: &str) {
         assert_eq!(format_duration(nanos), expected);
     }
+
+    #[rstest]
+    #[case(0, "0B")]
+    #[case(1023, "1023B")]
+    #[case(1024, "1.000KiB")]
+    #[case(1536, "1.500KiB")]
+    #[case(5 * 1024 * 1024, "5.000MiB")]
+    #[case(3 * 1024 * 1024 * 1024, "3.000GiB")]
+    fn test_format_bytes(#[case] bytes: usize, #[case] expected: &str) {
+        assert_eq!(format_bytes(bytes), expected);
+    }
 }
@@ -0,0 +1,165 @@
+use std::any::type_name;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use crate::ParseError;
+
+/// A named parameter of a puzzle (see [`crate::Solution::PARAMS`]), with its value for the actual
+/// input and the values it can be overridden with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    pub range: RangeInclusive<i64>,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: i64, range: RangeInclusive<i64>) -> Self {
+        Param {
+            name,
+            default,
+            range,
+        }
+    }
+}
+
+/// Values of the named parameters of a puzzle (see [`crate::Solution::PARAMS`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params(Vec<(Param, i64)>);
+
+impl Params {
+    pub fn new(params: &[Param]) -> Self {
+        Self(params.iter().map(|p| (p.clone(), p.default)).collect())
+    }
+
+    /// Value of the parameter `name`.
+    ///
+    /// Panics if the puzzle doesn't declare it.
+    pub fn get(&self, name: &str) -> i64 {
+        self.0
+            .iter()
+            .find(|(p, _)| p.name == name)
+            .map(|(_, v)| *v)
+            .unwrap_or_else(|| panic!("undeclared parameter {:?}", name))
+    }
+
+    /// Value of the parameter `name`, converted to the type the puzzle works with.
+    ///
+    /// Panics if the puzzle doesn't declare it.
+    pub fn try_get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, ParseError> {
+        let value = self.get(name);
+
+        T::try_from(value).map_err(|_| {
+            ParseError::input(format!(
+                "parameter {:?} to fit in {}, got {}",
+                name,
+                type_name::<T>(),
+                value
+            ))
+        })
+    }
+
+    /// Override the value of the parameter `name`, which must be declared by the puzzle and
+    /// within its range.
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        if let Some((p, v)) = self.0.iter_mut().find(|(p, _)| p.name == name) {
+            if !p.range.contains(&value) {
+                return Err(format!(
+                    "invalid value {} for {:?}, expected {}..={}",
+                    value,
+                    name,
+                    p.range.start(),
+                    p.range.end()
+                ));
+            }
+
+            *v = value;
+            return Ok(());
+        }
+
+        if self.0.is_empty() {
+            Err(format!("unknown parameter {:?}, none available", name))
+        } else {
+            Err(format!(
+                "unknown parameter {:?}, expected one of: {}",
+                name,
+                self.0
+                    .iter()
+                    .map(|(p, _)| p.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        }
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, (param, value)) in self.0.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}={}", param.name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_params() {
+        let mut params = Params::new(&[
+            Param::new("width", 101, 1..=1000),
+            Param::new("height", 103, 1..=1000),
+        ]);
+
+        assert_eq!(params.get("width"), 101);
+
+        params.set("width", 11).unwrap();
+
+        assert_eq!(params.get("width"), 11);
+        assert_eq!(params.to_string(), "width=11, height=103");
+        assert_eq!(
+            params.set("depth", 1).unwrap_err(),
+            "unknown parameter \"depth\", expected one of: width, height"
+        );
+        assert!(Params::new(&[]).set("width", 1).is_err());
+    }
+
+    #[rstest]
+    #[case::zero(0)]
+    #[case::negative(-3)]
+    #[case::too_big(1001)]
+    fn test_set_out_of_range(#[case] value: i64) {
+        let mut params = Params::new(&[Param::new("width", 101, 1..=1000)]);
+
+        assert_eq!(
+            params.set("width", value).unwrap_err(),
+            format!("invalid value {} for \"width\", expected 1..=1000", value)
+        );
+        assert_eq!(params.get("width"), 101);
+    }
+
+    #[rstest]
+    fn test_try_get() {
+        let params = Params::new(&[Param::new("offset", 1 << 40, 0..=1 << 50)]);
+
+        assert_eq!(params.try_get::<u64>("offset"), Ok(1 << 40));
+        assert_eq!(
+            params.try_get::<i32>("offset").unwrap_err().to_string(),
+            "expected parameter \"offset\" to fit in i32, got 1099511627776"
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "undeclared parameter")]
+    fn test_get_undeclared() {
+        Params::new(&[]).get("width");
+    }
+}
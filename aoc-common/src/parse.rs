@@ -0,0 +1,451 @@
+use std::any::type_name;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::PuzzleInput;
+
+/// An error in a puzzle input, pointing at the offending part of a line:
+///
+/// ```text
+/// line 3, column 4: expected a number
+///   3 | 12 x4 7
+///     |    ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    /// Column of the error in characters, starting at 1.
+    pub column: usize,
+    /// What was expected there, e.g. `a number` or `"->"`.
+    pub expected: String,
+    /// The line containing the error.
+    pub source: String,
+}
+
+impl ParseError {
+    /// Error at the `column`th character (starting at 0) of `source`, the `idx`th line of the
+    /// input (starting at 0).
+    pub fn new(idx: usize, source: &str, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: idx + 1,
+            column: column + 1,
+            expected: expected.into(),
+            source: source.to_owned(),
+        }
+    }
+
+    /// Error about the input as a whole, e.g. a missing section.
+    pub fn input(expected: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            source: String::new(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line == 0 {
+            return write!(f, "expected {}", self.expected);
+        }
+
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "  {} | {}", number, self.source)?;
+        write!(f, "  {} | {}^", margin, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of the input, which errors are reported against.
+///
+/// It can also be a part of a line, such as those returned by [`Line::split_pair`], in which case
+/// errors still point into the whole line.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    idx: usize,
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The `idx`th line of the input, starting at 0.
+    pub fn new(idx: usize, text: &'a str) -> Self {
+        Line {
+            idx,
+            source: text,
+            text,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The part `text` of the line, which must be a slice of it.
+    fn sub(&self, text: &'a str) -> Self {
+        Line { text, ..*self }
+    }
+
+    /// Offset in bytes of `token` in the whole line, if it is a slice of it.
+    fn offset_of(&self, token: &str) -> Option<usize> {
+        (token.as_ptr() as usize)
+            .checked_sub(self.source.as_ptr() as usize)
+            .filter(|&o| o + token.len() <= self.source.len() && self.source.is_char_boundary(o))
+    }
+
+    /// Error at the start of `token`, which should be a slice of the line, e.g. a part returned
+    /// by `split`. Otherwise, the error is at the start of the line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = self.offset_of(token).unwrap_or(0);
+        let column = self.source[..offset].chars().count();
+
+        ParseError::new(self.idx, self.source, column, expected)
+    }
+
+    /// Error at the end of the line, when something is missing.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        let end = self.offset_of(self.text).unwrap_or(0) + self.text.len();
+        let column = self.source[..end].chars().count();
+
+        ParseError::new(self.idx, self.source, column, expected)
+    }
+
+    /// Parse `token`, a slice of the line.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Parse the whole line.
+    pub fn value<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.parse(self.text, expected)
+    }
+
+    /// The parts of the line before and after the first `sep`, e.g. `("190", "10 19")` for
+    /// `190: 10 19` split on `": "`.
+    pub fn split_pair(&self, sep: &str) -> Result<(Line<'a>, Line<'a>), ParseError> {
+        self.text
+            .split_once(sep)
+            .map(|(a, b)| (self.sub(a), self.sub(b)))
+            .ok_or_else(|| self.error_at_end(format!("{:?}", sep)))
+    }
+
+    /// The rest of the line after `prefix`, which the line must start with.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Line<'a>, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.sub(rest))
+            .ok_or_else(|| self.error(self.text, format!("{:?}", prefix)))
+    }
+
+    /// A key and its value separated by `sep`, e.g. `("x00", 1)` for `x00: 1`.
+    pub fn key_value<V: FromStr>(
+        &self,
+        sep: &str,
+        expected: &str,
+    ) -> Result<(&'a str, V), ParseError> {
+        let (key, value) = self.split_pair(sep)?;
+
+        Ok((key.text, value.value(expected)?))
+    }
+
+    /// Parse the values separated by whitespace, e.g. `7 6 4 2 1`.
+    pub fn fields<T: FromStr>(&self, expected: &str) -> Result<Vec<T>, ParseError> {
+        self.text
+            .split_whitespace()
+            .map(|t| self.parse(t, expected))
+            .collect()
+    }
+
+    /// Parse the values separated by `sep`, e.g. `75,47,61` separated by `","`.
+    pub fn list<T: FromStr>(&self, sep: &str, expected: &str) -> Result<Vec<T>, ParseError> {
+        self.text
+            .split(sep)
+            .map(|t| self.parse(t, expected))
+            .collect()
+    }
+
+    /// Every integer in the line, ignoring what surrounds them, e.g. `[0, 4, 3, -3]` for
+    /// `p=0,4 v=3,-3`. A `-` right before digits is a sign.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        let bytes = self.text.as_bytes();
+        let mut ints = Vec::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = if i > 0 && bytes[i - 1] == b'-' {
+                i - 1
+            } else {
+                i
+            };
+            let end = bytes[i..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .map_or(bytes.len(), |n| i + n);
+            let token = &self.text[start..end];
+
+            ints.push(token.parse().map_err(|_| {
+                self.error(token, format!("an integer fitting in {}", type_name::<T>()))
+            })?);
+            i = end;
+        }
+
+        Ok(ints)
+    }
+
+    /// Exactly `N` integers in the line, see [`Line::ints`].
+    pub fn ints_n<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError> {
+        let ints = self.ints()?;
+        let found = ints.len();
+
+        ints.try_into().map_err(|_| {
+            let expected = format!("{} integers, found {}", N, found);
+
+            if found < N {
+                self.error_at_end(expected)
+            } else {
+                self.error(self.text, expected)
+            }
+        })
+    }
+}
+
+/// The lines of an input, to parse them with errors pointing into them.
+pub fn lines(input: &PuzzleInput) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, l)| Line::new(idx, l))
+}
+
+/// The blocks of lines of an input, separated by blank lines.
+pub fn blocks(input: &PuzzleInput) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = lines(input).peekable();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|l| l.text.is_empty()).is_some() {}
+        lines.peek()?;
+
+        Some(std::iter::from_fn(|| lines.next_if(|l| !l.text.is_empty())).collect())
+    })
+}
+
+/// Parse lines of `key<sep>value`, e.g. `x00: 1`, into a map.
+pub fn key_values<'a, K, V, C>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    sep: &str,
+    expected: &str,
+) -> Result<C, ParseError>
+where
+    K: From<&'a str>,
+    V: FromStr,
+    C: FromIterator<(K, V)>,
+{
+    lines
+        .into_iter()
+        .map(|line| {
+            let (key, value) = line.key_value(sep, expected)?;
+            Ok((K::from(key), value))
+        })
+        .collect()
+}
+
+/// Parse an input made of `N` columns of values separated by whitespace, e.g. `3   4`, into one
+/// list per column.
+pub fn columns<T: FromStr, const N: usize>(
+    input: &PuzzleInput,
+    expected: &str,
+) -> Result<[Vec<T>; N], ParseError> {
+    let mut columns: [Vec<T>; N] = std::array::from_fn(|_| Vec::with_capacity(input.len()));
+
+    for line in lines(input) {
+        let mut tokens = line.text.split_whitespace();
+
+        for column in columns.iter_mut() {
+            let token = tokens.next().ok_or_else(|| line.error_at_end(expected))?;
+            column.push(line.parse(token, expected)?);
+        }
+        if let Some(extra) = tokens.next() {
+            return Err(line.error(extra, "the end of the line"));
+        }
+    }
+
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_display() {
+        let err = ParseError::new(2, "12 x4 7", 3, "a number");
+
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 4: expected a number\n  3 | 12 x4 7\n    |    ^"
+        );
+        assert_eq!(
+            ParseError::input("a blank line").to_string(),
+            "expected a blank line"
+        );
+    }
+
+    #[rstest]
+    fn test_error_at_token() {
+        let line = Line::new(0, "é: 1 -> x");
+        let token = line.text().split(' ').nth(3).unwrap();
+
+        let err = line.error(token, "a number");
+
+        assert_eq!((err.line, err.column), (1, 9));
+        let elsewhere = String::from("x");
+        assert_eq!(line.error(&elsewhere, "x").column, 1);
+    }
+
+    #[rstest]
+    fn test_error_at_end() {
+        let err = Line::new(4, "abc").error_at_end("\":\"");
+
+        assert_eq!((err.line, err.column), (5, 4));
+    }
+
+    #[rstest]
+    fn test_parse() {
+        let line = Line::new(0, "12 x4");
+        let mut tokens = line.text().split(' ');
+
+        assert_eq!(
+            line.parse::<u32>(tokens.next().unwrap(), "a number"),
+            Ok(12)
+        );
+        assert_eq!(
+            line.parse::<u32>(tokens.next().unwrap(), "a number")
+                .unwrap_err()
+                .column,
+            4
+        );
+    }
+
+    #[rstest]
+    fn test_lines() {
+        let input = PuzzleInput::from("a\nb");
+        let err = lines(&input).last().unwrap().error_at_end("c");
+
+        assert_eq!(err.line, 2);
+    }
+
+    #[rstest]
+    fn test_blocks() {
+        let input = PuzzleInput::from("a\nb\n\n\nc\n\n");
+
+        let blocks = blocks(&input)
+            .map(|b| b.iter().map(|l| (l.idx, l.text())).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(blocks, vec![vec![(0, "a"), (1, "b")], vec![(4, "c")]]);
+    }
+
+    #[rstest]
+    fn test_split_pair() {
+        let line = Line::new(0, "190: 10 x");
+        let (target, values) = line.split_pair(": ").unwrap();
+
+        assert_eq!(target.value::<u32>("a number"), Ok(190));
+        assert_eq!(values.text(), "10 x");
+        assert_eq!(values.fields::<u32>("a number").unwrap_err().column, 9);
+        assert_eq!(values.error_at_end("x").column, 10);
+        assert_eq!(line.split_pair(" -> ").unwrap_err().column, 10);
+    }
+
+    #[rstest]
+    fn test_strip_prefix() {
+        let line = Line::new(0, "Register A: 729");
+
+        assert_eq!(
+            line.strip_prefix("Register A: ")
+                .unwrap()
+                .value::<u32>("a number"),
+            Ok(729)
+        );
+        assert_eq!(line.strip_prefix("Program: ").unwrap_err().column, 1);
+    }
+
+    #[rstest]
+    fn test_key_values() {
+        let input = PuzzleInput::from("x00: 1\ny01: 0");
+        let values: Vec<(String, u8)> = key_values(lines(&input), ": ", "a bit").unwrap();
+
+        assert_eq!(values, vec![("x00".to_owned(), 1), ("y01".to_owned(), 0)]);
+    }
+
+    #[rstest]
+    #[case("7 6 4", Ok(vec![7, 6, 4]))]
+    #[case("7  6", Ok(vec![7, 6]))]
+    #[case("7 x 4", Err(3))]
+    fn test_fields(#[case] text: &str, #[case] expected: Result<Vec<u32>, usize>) {
+        let res = Line::new(0, text).fields("a number").map_err(|e| e.column);
+
+        assert_eq!(res, expected);
+    }
+
+    #[rstest]
+    fn test_list() {
+        let line = Line::new(0, "75,47,61");
+
+        assert_eq!(line.list::<u8>(",", "a page"), Ok(vec![75, 47, 61]));
+        assert_eq!(line.list::<u8>(" ", "a page").unwrap_err().column, 1);
+    }
+
+    #[rstest]
+    #[case("p=0,4 v=3,-3", Ok(vec![0, 4, 3, -3]))]
+    #[case("Button A: X+94, Y-34", Ok(vec![94, -34]))]
+    #[case("no numbers - here", Ok(vec![]))]
+    #[case("x=1 y=99999999999", Err(7))]
+    fn test_ints(#[case] text: &str, #[case] expected: Result<Vec<i32>, usize>) {
+        let res = Line::new(0, text).ints().map_err(|e| e.column);
+
+        assert_eq!(res, expected);
+    }
+
+    #[rstest]
+    fn test_ints_unsigned() {
+        let err = Line::new(0, "3,-3").ints::<u8>().unwrap_err();
+
+        assert_eq!(err.column, 3);
+        assert_eq!(err.expected, "an integer fitting in u8");
+    }
+
+    #[rstest]
+    fn test_ints_n() {
+        let line = Line::new(0, "X=8400, Y=5400");
+
+        assert_eq!(line.ints_n::<u64, 2>(), Ok([8400, 5400]));
+        assert_eq!(line.ints_n::<u64, 3>().unwrap_err().column, 15);
+        assert_eq!(line.ints_n::<u64, 1>().unwrap_err().column, 1);
+    }
+
+    #[rstest]
+    fn test_columns() {
+        let input = PuzzleInput::from("3   4\n4   3");
+        let [left, right] = columns::<i32, 2>(&input, "a number").unwrap();
+
+        assert_eq!((left, right), (vec![3, 4], vec![4, 3]));
+        assert_eq!(columns::<i32, 3>(&input, "a number").unwrap_err().column, 6);
+        assert_eq!(columns::<i32, 1>(&input, "a number").unwrap_err().column, 5);
+    }
+}
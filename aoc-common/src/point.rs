@@ -0,0 +1,186 @@
+use std::num::TryFromIntError;
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Point<T>
+where
+    T: Clone + Copy,
+{
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T>
+where
+    T: Clone + Copy,
+{
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Add for Point<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T> Sub for Point<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T> Neg for Point<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> Mul<T> for Point<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+macro_rules! impl_manhattan {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl Point<$t> {
+                /// Manhattan distance between two points.
+                pub fn manhattan(&self, other: &Self) -> $u {
+                    self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+                }
+            }
+        )*
+    };
+}
+
+impl_manhattan!(i32 => u32, i64 => u64, isize => usize, u32 => u32, u64 => u64, usize => usize);
+
+macro_rules! impl_wrapping_offset {
+    ($($t:ty),*) => {
+        $(
+            impl Point<$t> {
+                /// Move by `delta`, wrapping around so the result stays within `(0, 0)..size`.
+                pub fn wrapping_offset(&self, delta: Self, size: Self) -> Self {
+                    Self::new(
+                        (self.x + delta.x).rem_euclid(size.x),
+                        (self.y + delta.y).rem_euclid(size.y),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping_offset!(i32, i64, isize);
+
+impl Point<usize> {
+    /// Move by `delta`, or `None` if either coordinate would go below zero or overflow.
+    pub fn checked_offset(&self, delta: Point<i64>) -> Option<Self> {
+        let x = self.x.checked_add_signed(delta.x.try_into().ok()?)?;
+        let y = self.y.checked_add_signed(delta.y.try_into().ok()?)?;
+
+        Some(Self::new(x, y))
+    }
+}
+
+impl From<Point<usize>> for Point<i64> {
+    fn from(p: Point<usize>) -> Self {
+        Self::new(p.x as i64, p.y as i64)
+    }
+}
+
+impl TryFrom<Point<i64>> for Point<usize> {
+    type Error = TryFromIntError;
+
+    fn try_from(p: Point<i64>) -> Result<Self, Self::Error> {
+        Ok(Self::new(p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(1, 5);
+
+        assert_eq!(a + b, Point::new(4, 3));
+        assert_eq!(a - b, Point::new(2, -7));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a * 3, Point::new(9, -6));
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), Point::new(3, 4), 7)]
+    #[case(Point::new(3, 4), Point::new(0, 0), 7)]
+    #[case(Point::new(-2, 5), Point::new(2, -5), 14)]
+    fn test_manhattan(#[case] a: Point<i64>, #[case] b: Point<i64>, #[case] expected: u64) {
+        assert_eq!(a.manhattan(&b), expected);
+    }
+
+    #[rstest]
+    fn test_manhattan_unsigned() {
+        let a = Point::<usize>::new(5, 1);
+
+        assert_eq!(a.manhattan(&Point::new(2, 3)), 5);
+    }
+
+    #[rstest]
+    #[case(Point::new(-1, 1), Some(Point::new(0, 2)))]
+    #[case(Point::new(-2, 0), None)]
+    #[case(Point::new(0, -2), None)]
+    fn test_checked_offset(#[case] delta: Point<i64>, #[case] expected: Option<Point<usize>>) {
+        let p = Point::<usize>::new(1, 1);
+
+        assert_eq!(p.checked_offset(delta), expected);
+    }
+
+    #[rstest]
+    #[case(Point::new(2, 3), Point::new(2, 0))]
+    #[case(Point::new(-2, -5), Point::new(9, 6))]
+    #[case(Point::new(23, 15), Point::new(1, 5))]
+    fn test_wrapping_offset(#[case] delta: Point<i32>, #[case] expected: Point<i32>) {
+        let size = Point::new(11, 7);
+
+        assert_eq!(
+            Point::<i32>::new(0, 4).wrapping_offset(delta, size),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_conversions() {
+        let p = Point::<usize>::new(3, 7);
+
+        assert_eq!(Point::<i64>::from(p), Point::new(3, 7));
+        assert_eq!(Point::<usize>::try_from(Point::<i64>::new(3, 7)), Ok(p));
+        assert!(Point::<usize>::try_from(Point::<i64>::new(-1, 7)).is_err());
+    }
+}
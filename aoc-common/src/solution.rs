@@ -0,0 +1,34 @@
+use crate::{Answer, Param, Params, ParseError, PuzzleInput};
+
+/// A day's puzzle solver.
+///
+/// The input is parsed once, then both parts are computed from the parsed representation. Parts
+/// that need to mutate their input are expected to work on a clone.
+pub trait Solution {
+    /// The parsed input, which can borrow from the puzzle input, e.g. a
+    /// [`GridView`](crate::GridView) of it.
+    type Input<'a>;
+
+    /// Named parameters of the puzzle, such as the size of a grid, with their values for the
+    /// actual input. They can be overridden within their range, e.g. to run the examples of the
+    /// puzzle.
+    const PARAMS: &'static [Param] = &[];
+
+    /// Parse the input, reporting where it's malformed instead of panicking.
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Parse the input using the given values of [`Self::PARAMS`]. Days with parameters convert
+    /// the ones they need with [`Params::try_get`] and keep them in their input, and implement
+    /// [`Self::parse`] with the defaults.
+    fn parse_with_params<'a>(
+        input: &'a PuzzleInput,
+        params: &Params,
+    ) -> Result<Self::Input<'a>, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(input: &Self::Input<'_>) -> Answer;
+}
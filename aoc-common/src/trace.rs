@@ -0,0 +1,193 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use tracing::Subscriber;
+use tracing_chrome::ChromeLayerBuilder;
+use tracing_flame::FlameLayer;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// A file recording every span, for a closer look at where the time goes than the span-close
+/// events printed by [`crate::tracing_init`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub path: PathBuf,
+    pub format: TraceFormat,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// Chrome's trace event JSON, to open in `chrome://tracing` or Perfetto.
+    #[default]
+    Chrome,
+    /// Folded stacks, to render with `inferno-flamegraph` or `flamegraph.pl`.
+    Folded,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "chrome" => Ok(TraceFormat::Chrome),
+            "folded" => Ok(TraceFormat::Folded),
+            _ => Err(format!(
+                "unknown trace format {:?}, expected chrome or folded",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for TraceFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceFormat::Chrome => write!(f, "chrome"),
+            TraceFormat::Folded => write!(f, "folded"),
+        }
+    }
+}
+
+/// Keeps the trace file open. It is completed when this is dropped, so it must be kept until the
+/// end of the program.
+#[must_use = "the trace is only written when the guard is dropped"]
+pub struct TraceGuard {
+    _guard: Option<Box<dyn Any>>,
+}
+
+impl TraceGuard {
+    pub(crate) fn none() -> Self {
+        TraceGuard { _guard: None }
+    }
+}
+
+/// A layer writing the spans at the debug level and above to the trace file, whatever the log
+/// filter.
+pub(crate) fn trace_layer<S>(
+    trace: &Trace,
+) -> io::Result<(Box<dyn Layer<S> + Send + Sync>, TraceGuard)>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + Send + Sync,
+{
+    let file = File::create(&trace.path)?;
+
+    let (layer, guard): (Box<dyn Layer<S> + Send + Sync>, Box<dyn Any>) = match trace.format {
+        TraceFormat::Chrome => {
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+            (
+                Box::new(layer.with_filter(LevelFilter::DEBUG)),
+                Box::new(guard),
+            )
+        }
+        TraceFormat::Folded => {
+            let layer = FlameLayer::new(BufWriter::new(file)).with_file_and_line(false);
+            let guard = layer.flush_on_drop();
+            (
+                Box::new(layer.with_filter(LevelFilter::DEBUG)),
+                Box::new(guard),
+            )
+        }
+    };
+
+    Ok((
+        layer,
+        TraceGuard {
+            _guard: Some(guard),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use rstest::rstest;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::Registry;
+
+    use super::*;
+
+    fn write_trace(format: TraceFormat) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "aoc-common-trace-{}-{}",
+            std::process::id(),
+            format
+        ));
+        let trace = Trace {
+            path: path.clone(),
+            format,
+        };
+
+        let (layer, guard) = trace_layer(&trace).unwrap();
+        let subscriber = Registry::default().with(layer);
+        // Spans are recorded with the name of their thread.
+        thread::Builder::new()
+            .name("day13".to_owned())
+            .spawn(move || {
+                tracing::subscriber::with_default(subscriber, || {
+                    let _solve = tracing::info_span!("solve", part = 1).entered();
+                    let _inner = tracing::debug_span!("find_prize").entered();
+                    tracing::trace_span!("ignored").in_scope(|| {});
+                });
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        drop(guard);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        content
+    }
+
+    #[rstest]
+    fn test_chrome_trace() {
+        let content = write_trace(TraceFormat::Chrome);
+        let events = serde_json::from_str::<Vec<serde_json::Value>>(&content).unwrap();
+        let names = events
+            .iter()
+            .filter(|e| e["ph"] == "B")
+            .map(|e| e["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, ["solve", "find_prize"]);
+        assert!(content.contains("\"day13\""));
+    }
+
+    #[rstest]
+    fn test_folded_trace() {
+        let content = write_trace(TraceFormat::Folded);
+        let stacks = content
+            .lines()
+            .map(|l| l.rsplit_once(' ').unwrap().0)
+            .collect::<Vec<_>>();
+
+        // Frames are prefixed with the thread id and module path.
+        let nested =
+            "-day13; aoc_common::trace::tests::solve; aoc_common::trace::tests::find_prize";
+        assert!(stacks.iter().any(|s| s.ends_with(nested)), "{}", content);
+        assert!(stacks.iter().all(|s| !s.contains("ignored")));
+    }
+
+    #[rstest]
+    #[case("chrome", TraceFormat::Chrome)]
+    #[case("folded", TraceFormat::Folded)]
+    fn test_parse_format(#[case] s: &str, #[case] expected: TraceFormat) {
+        assert_eq!(s.parse::<TraceFormat>(), Ok(expected));
+        assert_eq!(expected.to_string(), s);
+    }
+
+    #[rstest]
+    fn test_parse_format_invalid() {
+        assert!("flamegraph".parse::<TraceFormat>().is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde::Deserialize;
+
+pub const DEFAULT_ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// The known-correct answers of each day of a year, as stored in `answers.toml`:
+///
+/// ```toml
+/// [2024.day01]
+/// part1 = 2192892
+/// part2 = 22962826
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<u8, [Option<String>; 2]>);
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawDay {
+    part1: Option<RawAnswer>,
+    part2: Option<RawAnswer>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Int(i64),
+    Str(String),
+}
+
+impl RawAnswer {
+    fn into_string(self) -> String {
+        match self {
+            RawAnswer::Int(i) => i.to_string(),
+            RawAnswer::Str(s) => s,
+        }
+    }
+}
+
+impl Answers {
+    /// Load the answers of `year` from `path`. A missing file holds no answers.
+    pub fn load(path: &Path, year: u16) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s, year).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("unable to read {}: {}", path.display(), e)),
+        }
+    }
+
+    fn parse(s: &str, year: u16) -> Result<Self, String> {
+        let raw: BTreeMap<String, BTreeMap<String, RawDay>> =
+            toml::from_str(s).map_err(|e| e.to_string())?;
+
+        if let Some(key) = raw.keys().find(|k| k.parse::<u16>().is_err()) {
+            return Err(format!("invalid year {:?}, expected e.g. `2024`", key));
+        }
+
+        raw.into_iter()
+            .filter(|(key, _)| key.parse() == Ok(year))
+            .flat_map(|(_, days)| days)
+            .map(|(key, day)| {
+                let num = key
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse::<u8>().ok())
+                    .ok_or_else(|| format!("invalid day {:?}, expected e.g. `day07`", key))?;
+
+                let parts = [day.part1, day.part2].map(|p| p.map(RawAnswer::into_string));
+
+                Ok((num, parts))
+            })
+            .collect::<Result<_, String>>()
+            .map(Answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&day)?[part as usize - 1].as_deref()
+    }
+
+    /// Check an answer computed for `part` of `day` against the registered one.
+    pub fn check(&self, day: u8, part: u8, actual: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == actual => Verdict::Ok,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Ok,
+    Wrong { expected: String },
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Ok => f.pad("OK"),
+            Verdict::Wrong { .. } => f.pad("WRONG"),
+            Verdict::Missing => f.pad("MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn answers() -> Answers {
+        Answers::parse(
+            r#"
+            [2024.day03]
+            part1 = 174103751
+            part2 = 100411201
+
+            [2024.day17]
+            part1 = "6,0,6,3,0,2,3,1,6"
+
+            [2023.day04]
+            part1 = 42
+            "#,
+            2024,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_parse(answers: Answers) {
+        assert_eq!(answers.get(3, 1), Some("174103751"));
+        assert_eq!(answers.get(3, 2), Some("100411201"));
+        assert_eq!(answers.get(17, 1), Some("6,0,6,3,0,2,3,1,6"));
+        assert_eq!(answers.get(17, 2), None);
+        assert_eq!(answers.get(4, 1), None);
+    }
+
+    #[rstest]
+    #[case("[2024.foo]\npart1 = 1")]
+    #[case("[day03]\npart1 = 1")]
+    #[case("[2024.day03]\npart3 = 1")]
+    #[case("[2024.day03]\npart1 = 1.5")]
+    fn test_parse_invalid(#[case] s: &str) {
+        assert!(Answers::parse(s, 2024).is_err());
+    }
+
+    #[rstest]
+    fn test_check(answers: Answers) {
+        assert_eq!(answers.check(3, 1, "174103751"), Verdict::Ok);
+        assert_eq!(
+            answers.check(3, 2, "42"),
+            Verdict::Wrong {
+                expected: "100411201".to_owned()
+            }
+        );
+        assert_eq!(answers.check(17, 2, "42"), Verdict::Missing);
+    }
+
+    #[rstest]
+    fn test_load_missing_file() {
+        let answers = Answers::load(Path::new("/nonexistent/answers.toml"), 2024).unwrap();
+
+        assert_eq!(answers, Answers::default());
+    }
+
+    #[rstest]
+    fn test_registered_answers_are_valid() {
+        let answers = Answers::load(Path::new(DEFAULT_ANSWERS_FILE), 2024).unwrap();
+
+        assert_eq!(answers.get(3, 1), Some("174103751"));
+    }
+}
@@ -0,0 +1,176 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{Params, ParseError, PuzzleInput};
+use tracing::Dispatch;
+
+use crate::runner::{Runner, Timings};
+
+pub struct BenchOptions {
+    /// Number of untimed runs done before measuring.
+    pub warmup: usize,
+    /// Exact number of measured runs. When unset, runs are repeated until `budget` is spent.
+    pub runs: Option<usize>,
+    pub budget: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<f64>>();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    pub total: Stats,
+}
+
+/// Repeatedly run a day's solver on an already loaded input and compute statistics for each
+/// phase. Tracing is disabled while measuring so that span bookkeeping doesn't skew the results.
+pub fn bench(
+    runner: Runner,
+    input: &PuzzleInput,
+    params: &Params,
+    opts: &BenchOptions,
+) -> Result<BenchResult, ParseError> {
+    let samples = tracing::dispatcher::with_default(&Dispatch::none(), || {
+        for _ in 0..opts.warmup {
+            runner(input, params)?;
+        }
+
+        let mut samples: Vec<Timings> = Vec::new();
+        let start = Instant::now();
+
+        loop {
+            samples.push(runner(input, params)?.timings);
+
+            let done = match opts.runs {
+                Some(runs) => samples.len() >= runs,
+                None => start.elapsed() >= opts.budget,
+            };
+            if done {
+                break;
+            }
+        }
+
+        Ok(samples)
+    })?;
+
+    let stats = |f: fn(&Timings) -> Duration| {
+        Stats::from_samples(&samples.iter().map(f).collect::<Vec<Duration>>())
+    };
+
+    Ok(BenchResult {
+        runs: samples.len(),
+        parse: stats(|t| t.parse),
+        part1: stats(|t| t.part1),
+        part2: stats(|t| t.part2),
+        total: stats(Timings::total),
+    })
+}
+
+/// Parse a duration given as `500ms`, `2s` or a bare number of seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (value, scale) = if let Some(v) = s.strip_suffix("ms") {
+        (v, 0.001)
+    } else if let Some(v) = s.strip_suffix('s') {
+        (v, 1.0)
+    } else {
+        (s, 1.0)
+    };
+
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .and_then(|v| Duration::try_from_secs_f64(v * scale).ok())
+        .ok_or_else(|| format!("invalid duration: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(
+            stats,
+            Stats {
+                min: Duration::from_micros(1),
+                median: Duration::from_nanos(2500),
+                mean: Duration::from_nanos(2500),
+                stddev: Duration::from_nanos(1118),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_stats_odd_number_of_samples() {
+        let samples = [5, 1, 3].map(Duration::from_micros);
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.median, Duration::from_micros(3));
+    }
+
+    #[rstest]
+    #[case("2s", Duration::from_secs(2))]
+    #[case("2", Duration::from_secs(2))]
+    #[case("0.5", Duration::from_millis(500))]
+    #[case("250ms", Duration::from_millis(250))]
+    fn test_parse_duration(#[case] input: &str, #[case] expected: Duration) {
+        assert_eq!(parse_duration(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("abc")]
+    #[case("-1s")]
+    #[case("inf")]
+    #[case("NaNms")]
+    #[case("1e30s")]
+    fn test_parse_duration_invalid(#[case] input: &str) {
+        assert!(parse_duration(input).is_err());
+    }
+}
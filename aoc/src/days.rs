@@ -0,0 +1,67 @@
+use crate::runner::Day;
+
+/// Number of puzzles in a year.
+pub const LAST_DAY: u8 = 25;
+
+/// The solvers of every year, by day. A year's days are registered in order as they get solved.
+pub const YEARS: &[(u16, &[Day])] = &[(2024, &DAYS_2024)];
+
+const DAYS_2024: [Day; 25] = [
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+    Day::new::<day19::Day19>(),
+    Day::new::<day20::Day20>(),
+    Day::new::<day21::Day21>(),
+    Day::new::<day22::Day22>(),
+    Day::new::<day23::Day23>(),
+    Day::new::<day24::Day24>(),
+    Day::new::<day25::Day25>(),
+];
+
+/// The solvers registered for `year`, the first one being day 1.
+pub fn days(year: u16) -> Result<&'static [Day], String> {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map(|(_, days)| *days)
+        .ok_or_else(|| {
+            let known = YEARS.iter().map(|(y, _)| y.to_string()).collect::<Vec<_>>();
+            format!(
+                "no solutions for {}, expected one of: {}",
+                year,
+                known.join(", ")
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_days() {
+        assert_eq!(days(2024).unwrap().len(), 25);
+        assert_eq!(
+            days(2023).err().unwrap(),
+            "no solutions for 2023, expected one of: 2024"
+        );
+    }
+}
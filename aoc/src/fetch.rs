@@ -0,0 +1,399 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io};
+
+/// Where puzzles are fetched from, unless overridden with `--base-url` or `$AOC_BASE_URL`.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie.
+pub const SESSION_VAR: &str = "SESSION_COOKIE";
+
+/// File the session cookie is read from when it isn't in the environment.
+pub const DEFAULT_SESSION_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.session");
+
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session cookie was found in the environment or the session file.
+    NoSession { path: PathBuf },
+    /// The puzzle isn't available yet.
+    Locked { day: u8, remaining: Duration },
+    /// The server answered with an error status.
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    /// The request couldn't be sent or its response couldn't be read.
+    Transport { reason: String },
+    /// The server answered with a page that isn't a puzzle input.
+    InvalidInput { reason: String },
+    /// The input couldn't be written to the cache.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession { path } => write!(
+                f,
+                "no session cookie\nhint: set ${} or save it in {}",
+                SESSION_VAR,
+                path.display()
+            ),
+            FetchError::Locked { day, remaining } => {
+                let secs = remaining.as_secs();
+                write!(
+                    f,
+                    "day {} unlocks in {}h{:02}m{:02}s",
+                    day,
+                    secs / 3600,
+                    secs / 60 % 60,
+                    secs % 60
+                )
+            }
+            FetchError::Status { url, status, .. } => write!(f, "{} returned HTTP {}", url, status),
+            FetchError::Transport { reason } => write!(f, "request failed: {}", reason),
+            FetchError::InvalidInput { reason } => write!(f, "invalid puzzle input: {}", reason),
+            FetchError::Io { path, source } => {
+                write!(f, "unable to write {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Read the session cookie from `$SESSION_COOKIE`, or from `file` if it isn't set. A leading
+/// `session=` is accepted and removed.
+pub fn session(file: &Path) -> Result<String, FetchError> {
+    session_from(env::var(SESSION_VAR).ok(), file)
+}
+
+fn session_from(var: Option<String>, file: &Path) -> Result<String, FetchError> {
+    let raw = match var {
+        Some(v) if !v.trim().is_empty() => v,
+        _ => fs::read_to_string(file).unwrap_or_default(),
+    };
+
+    let cookie = raw.trim();
+    let cookie = cookie.strip_prefix("session=").unwrap_or(cookie);
+
+    if cookie.is_empty() {
+        return Err(FetchError::NoSession {
+            path: file.to_owned(),
+        });
+    }
+
+    Ok(cookie.to_owned())
+}
+
+/// HTTP client for the Advent of Code website, sending the session cookie with every request.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            agent,
+        }
+    }
+
+    /// GET `path`, relative to the base URL, and return the response body.
+    pub fn get(&self, path: &str) -> Result<String, FetchError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session));
+
+        read_response(&url, request.call())
+    }
+
+    /// POST a form to `path`, relative to the base URL, and return the response body.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session));
+
+        read_response(&url, request.send_form(form))
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, FetchError> {
+    match response {
+        Ok(r) => r.into_string().map_err(|e| FetchError::Transport {
+            reason: e.to_string(),
+        }),
+        Err(ureq::Error::Status(status, r)) => Err(FetchError::Status {
+            url: url.to_owned(),
+            status,
+            body: r.into_string().unwrap_or_default(),
+        }),
+        Err(ureq::Error::Transport(t)) => Err(FetchError::Transport {
+            reason: t.to_string(),
+        }),
+    }
+}
+
+/// What [`fetch_input`] did.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already in the cache and wasn't downloaded.
+    Cached,
+    /// The input was downloaded; its size in bytes.
+    Downloaded(usize),
+}
+
+/// Whether an input was already downloaded to `dest`.
+pub fn is_cached(dest: &Path) -> bool {
+    fs::metadata(dest).is_ok_and(|m| m.len() > 0)
+}
+
+/// Download the input of `day` to `dest`, unless it's already there and `force` isn't set.
+pub fn fetch_input(
+    client: &Client,
+    year: u16,
+    day: u8,
+    dest: &Path,
+    force: bool,
+) -> Result<Fetched, FetchError> {
+    if !force && is_cached(dest) {
+        return Ok(Fetched::Cached);
+    }
+
+    let remaining = unlock_time(year, day)
+        .duration_since(SystemTime::now())
+        .unwrap_or_default();
+    if !remaining.is_zero() {
+        return Err(FetchError::Locked { day, remaining });
+    }
+
+    let body = client
+        .get(&format!("/{}/day/{}/input", year, day))
+        .map_err(|e| match e {
+            // The error page explains what went wrong better than the status code
+            FetchError::Status { ref body, .. } => match explain_error_page(body) {
+                Some(reason) => FetchError::InvalidInput {
+                    reason: reason.to_owned(),
+                },
+                None => e,
+            },
+            e => e,
+        })?;
+    validate_input(&body)?;
+
+    let io_err = |e| FetchError::Io {
+        path: dest.to_owned(),
+        source: e,
+    };
+    if let Some(dir) = dest.parent() {
+        fs::create_dir_all(dir).map_err(io_err)?;
+    }
+    fs::write(dest, &body).map_err(io_err)?;
+
+    Ok(Fetched::Downloaded(body.len()))
+}
+
+/// Check that a response body looks like a puzzle input rather than an error page.
+fn validate_input(body: &str) -> Result<(), FetchError> {
+    let invalid = |reason: &str| {
+        Err(FetchError::InvalidInput {
+            reason: reason.to_owned(),
+        })
+    };
+
+    if let Some(reason) = explain_error_page(body) {
+        return invalid(reason);
+    }
+
+    let start = body.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return invalid("got an HTML page");
+    }
+    if body.trim().is_empty() {
+        return invalid("empty response");
+    }
+
+    Ok(())
+}
+
+/// Explanation of the error pages returned instead of an input.
+fn explain_error_page(body: &str) -> Option<&'static str> {
+    if body.contains("Puzzle inputs differ by user") {
+        Some("not logged in, the session cookie is probably expired")
+    } else if body.contains("before it unlocks") {
+        Some("the puzzle isn't unlocked yet")
+    } else {
+        None
+    }
+}
+
+/// Puzzles unlock at midnight EST (05:00 UTC) on the day of December.
+fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    let secs = days * 86400 + 5 * 3600;
+
+    UNIX_EPOCH + Duration::from_secs(secs as u64)
+}
+
+/// Number of days between 1970-01-01 and the given date, in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::testing::{serve_once, temp_path};
+
+    #[rstest]
+    fn test_fetch_input() {
+        let (url, server) = serve_once(200, "1 2 3\n4 5 6\n");
+        let client = Client::new(&url, "abc123".to_owned());
+        let dest = temp_path("day01.txt");
+        let _ = fs::remove_file(&dest);
+
+        let res = fetch_input(&client, 2024, 1, &dest, false).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(res, Fetched::Downloaded(12));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "1 2 3\n4 5 6\n");
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("Cookie: session=abc123"));
+        assert!(request.contains(&format!("User-Agent: {}", USER_AGENT)));
+    }
+
+    #[rstest]
+    fn test_fetch_input_uses_cache() {
+        // Nothing listens on the base URL, so any request would fail
+        let client = Client::new("http://127.0.0.1:1", "abc123".to_owned());
+        let dest = temp_path("day02.txt");
+        fs::write(&dest, "cached").unwrap();
+
+        let res = fetch_input(&client, 2024, 2, &dest, false).unwrap();
+
+        assert_eq!(res, Fetched::Cached);
+    }
+
+    #[rstest]
+    fn test_fetch_input_rejects_error_page() {
+        let (url, _server) = serve_once(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let client = Client::new(&url, "expired".to_owned());
+        let dest = temp_path("day03.txt");
+
+        let err = fetch_input(&client, 2024, 3, &dest, true).unwrap_err();
+
+        assert!(matches!(err, FetchError::InvalidInput { .. }));
+        assert!(err.to_string().contains("session cookie"));
+        assert!(!dest.exists());
+    }
+
+    #[rstest]
+    fn test_fetch_input_http_error() {
+        let (url, _server) = serve_once(500, "");
+        let client = Client::new(&url, "abc123".to_owned());
+
+        let err = fetch_input(&client, 2024, 4, &temp_path("day04.txt"), true).unwrap_err();
+
+        assert!(matches!(err, FetchError::Status { status: 500, .. }));
+    }
+
+    #[rstest]
+    fn test_fetch_input_refuses_locked_puzzle() {
+        let client = Client::new("http://127.0.0.1:1", "abc123".to_owned());
+
+        let err = fetch_input(&client, 9999, 1, &temp_path("day99.txt"), true).unwrap_err();
+
+        assert!(matches!(err, FetchError::Locked { day: 1, .. }));
+    }
+
+    #[rstest]
+    #[case("Puzzle inputs differ by user.  Please log in to get your puzzle input.")]
+    #[case("Please don't repeatedly request this endpoint before it unlocks!")]
+    #[case("<!DOCTYPE html>\n<html lang=\"en-us\">")]
+    #[case("  \n")]
+    fn test_validate_input_invalid(#[case] body: &str) {
+        assert!(validate_input(body).is_err());
+    }
+
+    #[rstest]
+    fn test_validate_input() {
+        assert!(validate_input("3   4\n4   3\n").is_ok());
+    }
+
+    #[rstest]
+    #[case(Some("abc123".to_owned()), "abc123")]
+    #[case(Some("session=abc123\n".to_owned()), "abc123")]
+    fn test_session_from_env(#[case] var: Option<String>, #[case] expected: &str) {
+        let session = session_from(var, Path::new("/nonexistent/.session")).unwrap();
+
+        assert_eq!(session, expected);
+    }
+
+    #[rstest]
+    fn test_session_from_file() {
+        let path = temp_path("session");
+        fs::write(&path, "def456\n").unwrap();
+
+        assert_eq!(session_from(None, &path).unwrap(), "def456");
+        assert_eq!(session_from(Some(String::new()), &path).unwrap(), "def456");
+    }
+
+    #[rstest]
+    fn test_session_missing() {
+        let err = session_from(None, Path::new("/nonexistent/.session")).unwrap_err();
+
+        assert!(matches!(err, FetchError::NoSession { .. }));
+    }
+
+    #[rstest]
+    fn test_unlock_time() {
+        let unlock = unlock_time(2024, 1).duration_since(UNIX_EPOCH).unwrap();
+
+        // 2024-12-01T05:00:00Z
+        assert_eq!(unlock.as_secs(), 1733029200);
+    }
+}
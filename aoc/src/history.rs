@@ -0,0 +1,343 @@
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::bench::{BenchResult, Stats};
+
+const HEADER: &str = "timestamp,commit,label,day,phase,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// A single benchmark measurement of one phase of a day, as stored in the history file.
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub commit: String,
+    pub label: String,
+    pub day: u8,
+    pub phase: String,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+impl Record {
+    pub fn from_result(day: u8, result: &BenchResult, label: &str) -> Vec<Record> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let commit = current_commit();
+
+        [
+            ("parse", &result.parse),
+            ("part1", &result.part1),
+            ("part2", &result.part2),
+            ("total", &result.total),
+        ]
+        .into_iter()
+        .map(|(phase, stats)| Record {
+            timestamp,
+            commit: commit.clone(),
+            label: label.to_owned(),
+            day,
+            phase: phase.to_owned(),
+            runs: result.runs,
+            stats: stats.clone(),
+        })
+        .collect()
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit,
+            self.label,
+            self.day,
+            self.phase,
+            self.runs,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.mean.as_nanos(),
+            self.stats.stddev.as_nanos(),
+        )
+    }
+
+    fn from_csv(line: &str) -> Result<Self, String> {
+        let fields = line.split(',').collect::<Vec<&str>>();
+        if fields.len() != 10 {
+            return Err(format!("expected 10 fields, got {}", fields.len()));
+        }
+
+        let int = |idx: usize| {
+            fields[idx]
+                .parse::<u64>()
+                .map_err(|e| format!("invalid value {:?}: {}", fields[idx], e))
+        };
+        let duration = |idx: usize| int(idx).map(Duration::from_nanos);
+
+        Ok(Record {
+            timestamp: int(0)?,
+            commit: fields[1].to_owned(),
+            label: fields[2].to_owned(),
+            day: u8::try_from(int(3)?).map_err(|_| format!("invalid day {:?}", fields[3]))?,
+            phase: fields[4].to_owned(),
+            runs: usize::try_from(int(5)?)
+                .map_err(|_| format!("invalid number of runs {:?}", fields[5]))?,
+            stats: Stats {
+                min: duration(6)?,
+                median: duration(7)?,
+                mean: duration(8)?,
+                stddev: duration(9)?,
+            },
+        })
+    }
+}
+
+pub fn append(path: &Path, records: &[Record]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+
+    for r in records {
+        writeln!(file, "{}", r.to_csv())?;
+    }
+
+    Ok(())
+}
+
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let file =
+        fs::File::open(path).map_err(|e| format!("unable to open {}: {}", path.display(), e))?;
+
+    parse(BufReader::new(file))
+}
+
+fn parse(reader: impl BufRead) -> Result<Vec<Record>, String> {
+    reader
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !matches!(l, Ok(l) if l.is_empty() || l == HEADER))
+        .map(|(idx, l)| {
+            let l = l.map_err(|e| e.to_string())?;
+            Record::from_csv(&l).map_err(|e| format!("line {}: {}", idx + 1, e))
+        })
+        .collect()
+}
+
+/// The change of a phase's median time between a reference run and the latest one.
+#[derive(Debug, PartialEq)]
+pub struct Comparison<'a> {
+    pub phase: &'a str,
+    pub before: &'a Record,
+    pub after: &'a Record,
+}
+
+impl Comparison<'_> {
+    /// Relative change of the median, in percent. Positive values are slowdowns.
+    ///
+    /// Returns `None` if the median went up from zero, which is no percentage.
+    pub fn change(&self) -> Option<f64> {
+        let before = self.before.stats.median.as_nanos() as f64;
+        let after = self.after.stats.median.as_nanos() as f64;
+
+        if before == 0.0 {
+            return (after == 0.0).then_some(0.0);
+        }
+
+        Some((after - before) / before * 100.0)
+    }
+
+    /// Going up from zero is a regression whatever the threshold.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_none_or(|c| c > threshold)
+    }
+}
+
+/// Compare the latest measurements of a day with either the run just before them, or the latest
+/// run recorded with the `baseline` label.
+pub fn compare<'a>(records: &'a [Record], day: u8, baseline: Option<&str>) -> Vec<Comparison<'a>> {
+    let mut comparisons = Vec::new();
+
+    for phase in ["parse", "part1", "part2", "total"] {
+        let mut runs = records
+            .iter()
+            .filter(|r| r.day == day && r.phase == phase)
+            .collect::<Vec<&Record>>();
+        runs.sort_by_key(|r| r.timestamp);
+
+        let Some((after, previous)) = runs.split_last() else {
+            continue;
+        };
+
+        let before = match baseline {
+            Some(label) => previous.iter().rev().find(|r| r.label == label),
+            None => previous.last(),
+        };
+
+        if let Some(before) = before {
+            comparisons.push(Comparison {
+                phase,
+                before,
+                after,
+            });
+        }
+    }
+
+    comparisons
+}
+
+fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_owned())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::testing::temp_path;
+
+    fn record(timestamp: u64, label: &str, phase: &str, median_us: u64) -> Record {
+        let d = Duration::from_micros(median_us);
+
+        Record {
+            timestamp,
+            commit: "abc1234".to_owned(),
+            label: label.to_owned(),
+            day: 5,
+            phase: phase.to_owned(),
+            runs: 10,
+            stats: Stats {
+                min: d,
+                median: d,
+                mean: d,
+                stddev: Duration::ZERO,
+            },
+        }
+    }
+
+    #[rstest]
+    fn test_csv_roundtrip() {
+        let r = record(1734000000, "baseline", "part2", 1234);
+
+        assert_eq!(Record::from_csv(&r.to_csv()), Ok(r));
+    }
+
+    #[rstest]
+    fn test_append_and_load() {
+        // The cache directory is created if needed.
+        let dir = temp_path("bench-cache");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("bench-history-2024.csv");
+
+        append(&path, &[record(1, "", "parse", 10)]).unwrap();
+        append(&path, &[record(2, "", "parse", 12)]).unwrap();
+
+        assert_eq!(
+            load(&path).unwrap(),
+            vec![record(1, "", "parse", 10), record(2, "", "parse", 12)]
+        );
+    }
+
+    #[rstest]
+    fn test_parse_skips_header() {
+        let input = format!("{}\n{}\n", HEADER, record(1, "", "parse", 10).to_csv());
+
+        let records = parse(input.as_bytes()).unwrap();
+
+        assert_eq!(records, vec![record(1, "", "parse", 10)]);
+    }
+
+    #[rstest]
+    fn test_parse_reports_line_number() {
+        let input = format!("{}\n1,2,3\n", HEADER);
+
+        let err = parse(input.as_bytes()).unwrap_err();
+
+        assert_eq!(err, "line 2: expected 10 fields, got 3");
+    }
+
+    #[rstest]
+    fn test_compare_with_previous_run() {
+        let records = vec![
+            record(1, "baseline", "part1", 100),
+            record(2, "", "part1", 200),
+            record(3, "", "part1", 250),
+        ];
+
+        let res = compare(&records, 5, None);
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].before.timestamp, 2);
+        assert_eq!(res[0].after.timestamp, 3);
+        assert_eq!(res[0].change(), Some(25.0));
+        assert!(res[0].is_regression(10.0));
+        assert!(!res[0].is_regression(30.0));
+    }
+
+    #[rstest]
+    fn test_compare_with_baseline() {
+        let records = vec![
+            record(1, "baseline", "part1", 100),
+            record(2, "", "part1", 200),
+            record(3, "", "part1", 250),
+        ];
+
+        let res = compare(&records, 5, Some("baseline"));
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].before.timestamp, 1);
+        assert_eq!(res[0].change(), Some(150.0));
+    }
+
+    #[rstest]
+    #[case(0, 0, Some(0.0), false)]
+    #[case(0, 10, None, true)]
+    #[case(10, 0, Some(-100.0), false)]
+    fn test_change_from_zero(
+        #[case] before_us: u64,
+        #[case] after_us: u64,
+        #[case] expected: Option<f64>,
+        #[case] regression: bool,
+    ) {
+        let before = record(1, "", "part1", before_us);
+        let after = record(2, "", "part1", after_us);
+        let comparison = Comparison {
+            phase: "part1",
+            before: &before,
+            after: &after,
+        };
+
+        assert_eq!(comparison.change(), expected);
+        assert_eq!(comparison.is_regression(10.0), regression);
+    }
+
+    #[rstest]
+    fn test_parse_day_out_of_range() {
+        let err = parse("1,abc1234,,256,part1,10,1,1,1,0".as_bytes()).unwrap_err();
+
+        assert_eq!(err, "line 1: invalid day \"256\"");
+    }
+
+    #[rstest]
+    fn test_compare_without_reference() {
+        let records = vec![record(1, "", "part1", 100)];
+
+        assert!(compare(&records, 5, None).is_empty());
+        assert!(compare(&records, 6, None).is_empty());
+    }
+}
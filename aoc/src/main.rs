@@ -0,0 +1,1036 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use aoc_common::{
+    cache_dir,
+    config,
+    input_file,
+    input_path,
+    tracing_init,
+    tracing_init_with_writer,
+    Answer,
+    Config,
+    InputSource,
+    Params,
+    PuzzleInput,
+    Trace,
+    TraceFormat,
+    CONFIG_VAR,
+    DEFAULT_YEAR,
+};
+use clap::{Args, Parser, Subcommand};
+
+use crate::answers::{Answers, Verdict, DEFAULT_ANSWERS_FILE};
+use crate::bench::{bench, parse_duration, BenchOptions};
+use crate::days::LAST_DAY;
+use crate::fetch::{
+    fetch_input,
+    is_cached,
+    Client,
+    Fetched,
+    DEFAULT_BASE_URL,
+    DEFAULT_SESSION_FILE,
+};
+use crate::history::Record;
+use crate::report::{
+    print_bench_result,
+    print_comparisons,
+    print_json,
+    print_output,
+    print_run_summary,
+    print_status,
+    print_verdict,
+    OutputFormat,
+    TableFormat,
+};
+use crate::runner::{run_all, RunOutcome, Runner};
+use crate::scaffold::{new_day, DayCrate, WORKSPACE_ROOT};
+use crate::status::day_status;
+use crate::submit::{submit, GuessLog, Outcome, GUESS_LOG_FILE};
+
+mod answers;
+mod bench;
+mod days;
+mod fetch;
+mod history;
+mod report;
+mod runner;
+mod scaffold;
+mod status;
+mod submit;
+#[cfg(test)]
+mod testing;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOC: aoc_common::CountingAllocator = aoc_common::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code solutions")]
+#[command(subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Configuration file. Defaults to `aoc.toml` at the root of the workspace.
+    #[arg(long, global = true, env = CONFIG_VAR)]
+    config: Option<PathBuf>,
+
+    /// Year of the puzzles.
+    #[arg(long, global = true, env = "AOC_YEAR")]
+    year: Option<u16>,
+
+    /// Tracing filter, overriding $RUST_LOG and the configuration (e.g. `debug`, `day14=trace`).
+    #[arg(long, global = true)]
+    log_filter: Option<String>,
+
+    /// Also write every span to this file, whatever the tracing filter.
+    #[arg(long, global = true, env = "AOC_TRACE")]
+    trace: Option<PathBuf>,
+
+    /// Format of the `--trace` file: `chrome` (trace event JSON for `chrome://tracing` or Perfetto)
+    /// or `folded` (stacks for `inferno-flamegraph` or `flamegraph.pl`).
+    #[arg(long, global = true, env = "AOC_TRACE_FORMAT", default_value_t = TraceFormat::Chrome)]
+    trace_format: TraceFormat,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions and print their answers.
+    Run(RunArgs),
+    /// Run the solutions repeatedly and report timing statistics for each phase.
+    Bench(BenchArgs),
+    /// Compare the latest benchmark results with a previous run and flag regressions.
+    Compare(CompareArgs),
+    /// Download puzzle inputs to the input directory.
+    Fetch(FetchArgs),
+    /// Run the solutions and check their answers against the registered ones.
+    Verify(VerifyArgs),
+    /// Run a day and submit the answer of one of its parts.
+    Submit(SubmitArgs),
+    /// Create the crate of a new day from the template and add it to the workspace.
+    New(NewArgs),
+    /// Show the progress of every day: implemented parts, inputs, full input tests and timings.
+    Status(StatusArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day(s) to run: a single day (`7`), an inclusive range (`3..9`) or `all`.
+    #[arg(required = true)]
+    days: Option<Days>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// File containing the known answers; wrong answers are marked in the output.
+    #[arg(long, default_value = DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+
+    /// Number of days run at once when several are selected. Defaults to the number of CPUs.
+    /// A day that times out is left running in the background and no longer counts.
+    #[arg(short, long)]
+    jobs: Option<usize>,
+
+    /// Time after which a day is given up on when several are selected (e.g. `500ms`, `60s`).
+    #[arg(long, default_value = "60s", value_parser = parse_duration)]
+    timeout: Duration,
+
+    /// Output format. Traces are written to stderr with `json`.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file (`-` for stdin) instead of the input directory. Only valid
+    /// when a single day is selected.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ParamArgs {
+    /// Override a puzzle parameter of the selected days, e.g. `--param width=11`. Can be repeated.
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+}
+
+impl ParamArgs {
+    /// Resolve the solvers of `days` of `year` along with their parameters: the defaults,
+    /// overridden by the configuration file, then by the command line.
+    ///
+    /// The days at the end of the range that aren't solved yet are left out, so that `all` selects
+    /// the days solved so far.
+    fn resolve(&self, year: u16, days: &Days) -> Result<BTreeMap<u8, (Runner, Params)>, String> {
+        self.resolve_with(year, days, config())
+    }
+
+    fn resolve_with(
+        &self,
+        year: u16,
+        days: &Days,
+        config: &Config,
+    ) -> Result<BTreeMap<u8, (Runner, Params)>, String> {
+        let solvers = days::days(year)?;
+        let (start, end) = (*days.0.start(), *days.0.end());
+
+        if start as usize > solvers.len() {
+            return Err(format!("Day {:02}: no solution for {} yet", start, year));
+        }
+
+        (start..=end.min(solvers.len() as u8))
+            .map(|day| {
+                let solver = solvers[day as usize - 1];
+                let mut params = Params::new(solver.params);
+
+                for (name, value) in config.params(year, day) {
+                    params
+                        .set(name, value)
+                        .map_err(|e| format!("aoc.toml: day {:02}: {}", day, e))?;
+                }
+                for (name, value) in &self.params {
+                    params
+                        .set(name, *value)
+                        .map_err(|e| format!("Day {:02}: {}", day, e))?;
+                }
+
+                Ok((day, (solver.run, params)))
+            })
+            .collect()
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got {:?}", s))?;
+    let value = value
+        .trim()
+        .parse::<i64>()
+        .map_err(|e| format!("invalid value {:?}: {}", value, e))?;
+
+    Ok((name.trim().to_owned(), value))
+}
+
+impl InputArgs {
+    fn check(&self, days: &Days) -> Result<(), String> {
+        if self.input.is_some() && days.0.start() != days.0.end() {
+            return Err("--input can only be used with a single day".to_owned());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day(s) to benchmark: a single day (`7`), an inclusive range (`3..9`) or `all`.
+    days: Days,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Number of untimed runs done before measuring [default: 3]
+    #[arg(long, env = "AOC_BENCH_WARMUP")]
+    warmup: Option<usize>,
+
+    /// Exact number of measured runs. Overrides `--budget`.
+    #[arg(long)]
+    runs: Option<usize>,
+
+    /// Time to spend measuring each day (e.g. `500ms`, `5s`) [default: 3s]
+    #[arg(long, env = "AOC_BENCH_BUDGET", value_parser = parse_duration)]
+    budget: Option<Duration>,
+
+    /// Label stored with the results, to be used as a baseline by `compare`.
+    #[arg(long, default_value = "", value_parser = parse_label)]
+    label: String,
+
+    /// Don't record the results in the history file.
+    #[arg(long)]
+    no_save: bool,
+
+    #[command(flatten)]
+    history: HistoryArgs,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Args)]
+struct CompareArgs {
+    /// Day(s) to compare: a single day (`7`), an inclusive range (`3..9`) or `all`.
+    days: Days,
+
+    /// Compare against the latest run with this label instead of the previous run.
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// Slowdown of the median time, in percent, above which a phase is flagged as a regression.
+    #[arg(long, default_value_t = 5.0)]
+    threshold: f64,
+
+    #[command(flatten)]
+    history: HistoryArgs,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day(s) to fetch: a single day (`7`), an inclusive range (`3..9`) or `all`.
+    days: Days,
+
+    /// Download the input again even if it's already in the input directory.
+    #[arg(long)]
+    force: bool,
+
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part to submit.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    server: ServerArgs,
+
+    #[command(flatten)]
+    params: ParamArgs,
+
+    /// File recording every answer submitted. Defaults to `guesses.log` in the cache directory.
+    #[arg(long)]
+    log: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct StatusArgs {
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = TableFormat::Terminal)]
+    format: TableFormat,
+
+    /// Don't run the full input tests, which builds and runs every day.
+    #[arg(long)]
+    skip_tests: bool,
+
+    /// File containing the known answers, used to count the stars.
+    #[arg(long, default_value = DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+
+    #[command(flatten)]
+    history: HistoryArgs,
+}
+
+#[derive(Args)]
+struct ServerArgs {
+    /// Base URL of the Advent of Code website.
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// File containing the session cookie, used when $SESSION_COOKIE isn't set. Defaults to
+    /// `.session` at the root of the workspace.
+    #[arg(long, env = "AOC_SESSION_FILE")]
+    session_file: Option<PathBuf>,
+}
+
+impl ServerArgs {
+    fn client(&self) -> Result<Client, String> {
+        let file = self
+            .session_file
+            .clone()
+            .or_else(|| config().session_file.clone())
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_SESSION_FILE));
+        let session = fetch::session(&file).map_err(|e| e.to_string())?;
+
+        Ok(Client::new(&self.base_url, session))
+    }
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day(s) to verify: a single day (`7`), an inclusive range (`3..9`) or `all`.
+    #[arg(default_value = "all")]
+    days: Days,
+
+    /// File containing the known answers.
+    #[arg(long, default_value = DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Args)]
+struct HistoryArgs {
+    /// Benchmark history file. Defaults to `bench-history-<year>.csv` in the cache directory.
+    #[arg(long)]
+    history: Option<PathBuf>,
+}
+
+impl HistoryArgs {
+    fn path(&self, year: u16) -> PathBuf {
+        self.history
+            .clone()
+            .unwrap_or_else(|| cache_dir().join(format!("bench-history-{}.csv", year)))
+    }
+}
+
+fn parse_label(s: &str) -> Result<String, String> {
+    if s.contains([',', '\n']) {
+        return Err("labels can't contain commas or newlines".to_owned());
+    }
+
+    Ok(s.to_owned())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Days(RangeInclusive<u8>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |d: &str| match d.parse::<u8>() {
+            Ok(d) if (1..=LAST_DAY).contains(&d) => Ok(d),
+            _ => Err(format!("invalid day: {}", d)),
+        };
+
+        if s == "all" {
+            return Ok(Days(1..=LAST_DAY));
+        }
+
+        let range = match s.split_once("..") {
+            Some((start, end)) => {
+                let end = end.strip_prefix('=').unwrap_or(end);
+                parse_day(start)?..=parse_day(end)?
+            }
+            None => {
+                let d = parse_day(s)?;
+                d..=d
+            }
+        };
+
+        if range.is_empty() {
+            return Err(format!("empty range: {}", s));
+        }
+
+        Ok(Days(range))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let config = match Config::init(cli.config.as_deref()) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let year = cli.year.or(config.year).unwrap_or(DEFAULT_YEAR);
+
+    let run_args = match &cli.command {
+        Some(Command::Run(args)) => args,
+        _ => &cli.run,
+    };
+    let trace = cli.trace.map(|path| Trace {
+        path,
+        format: cli.trace_format,
+    });
+    let traced = if run_args.format == OutputFormat::Json {
+        tracing_init_with_writer(std::io::stderr, cli.log_filter.as_deref(), trace.as_ref())
+    } else {
+        tracing_init(cli.log_filter.as_deref(), trace.as_ref())
+    };
+    // Kept until the end so that the trace file is complete.
+    let _trace_guard = match traced {
+        Ok(guard) => guard,
+        Err(e) => {
+            eprintln!(
+                "Unable to write the trace to {}: {}",
+                trace.map_or_else(String::new, |t| t.path.display().to_string()),
+                e
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.command {
+        Some(Command::Run(args)) => run(args, year),
+        Some(Command::Bench(args)) => run_bench(args, year),
+        Some(Command::Compare(args)) => run_compare(args, year),
+        Some(Command::Fetch(args)) => run_fetch(args, year),
+        Some(Command::Verify(args)) => run_verify(args, year),
+        Some(Command::Submit(args)) => run_submit(args, year),
+        Some(Command::New(args)) => run_new(args, year),
+        Some(Command::Status(args)) => run_status(args, year),
+        None => run(cli.run, year),
+    }
+}
+
+fn load_input(year: u16, day: u8, explicit: Option<&Path>) -> Option<PuzzleInput> {
+    let source = InputSource::resolve(&input_file(year, day), explicit);
+
+    match source.read_input() {
+        Ok(input) => Some(input),
+        Err(e) => {
+            eprintln!("Day {:02}: {}", day, e);
+            None
+        }
+    }
+}
+
+fn run(args: RunArgs, year: u16) -> ExitCode {
+    let days = args.days.expect("days are required");
+    if let Err(e) = args.input.check(&days) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+
+    let answers = Answers::load(&args.answers, year).unwrap_or_else(|e| {
+        eprintln!("Ignoring known answers: {}", e);
+        Answers::default()
+    });
+    let solvers = match args.params.resolve(year, &days) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if days.0.start() != days.0.end() {
+        let jobs = args
+            .jobs
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+
+        return run_many(year, solvers, jobs, args.timeout, args.format, &answers);
+    }
+
+    let day = *days.0.start();
+    let _span = tracing::info_span!("day", day).entered();
+
+    let Some(input) = load_input(year, day, args.input.input.as_deref()) else {
+        if args.format == OutputFormat::Json {
+            print_json(&BTreeMap::new(), &[day]);
+        }
+        return ExitCode::FAILURE;
+    };
+    let (runner, params) = &solvers[&day];
+    let outcome = match runner(&input, params) {
+        Ok(output) => RunOutcome::Done(output),
+        Err(e) => RunOutcome::InvalidInput(e),
+    };
+
+    let code = match outcome {
+        RunOutcome::Done(_) => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    };
+
+    match (args.format, outcome) {
+        (OutputFormat::Text, RunOutcome::Done(output)) => print_output(day, &output, &answers),
+        (OutputFormat::Text, RunOutcome::InvalidInput(e)) => {
+            eprintln!("Day {:02}: invalid input: {}", day, e)
+        }
+        (OutputFormat::Text, _) => unreachable!("the day ran on this thread"),
+        (OutputFormat::Json, outcome) => print_json(&BTreeMap::from([(day, outcome)]), &[]),
+    }
+
+    code
+}
+
+/// Run several days concurrently, isolating them from each other's panics and timeouts, then
+/// print their outputs in order followed by a summary.
+fn run_many(
+    year: u16,
+    solvers: BTreeMap<u8, (Runner, Params)>,
+    jobs: usize,
+    timeout: Duration,
+    format: OutputFormat,
+    answers: &Answers,
+) -> ExitCode {
+    let start = Instant::now();
+    let mut outcomes = BTreeMap::new();
+    let mut missing = Vec::new();
+
+    let runs: Vec<_> = solvers
+        .into_iter()
+        .filter_map(
+            |(day, (runner, params))| match load_input(year, day, None) {
+                Some(input) => Some((day, runner, params, input)),
+                None => {
+                    missing.push(day);
+                    None
+                }
+            },
+        )
+        .collect();
+
+    if cfg!(feature = "alloc-stats") && jobs > 1 && runs.len() > 1 {
+        eprintln!(
+            "Allocations are counted for the whole process and mix the days running at the same \
+             time, use --jobs 1 to count those of each day"
+        );
+    }
+
+    run_all(runs, jobs, timeout, |day, outcome| {
+        outcomes.insert(day, outcome);
+    });
+
+    match format {
+        OutputFormat::Text => {
+            for (idx, (day, outcome)) in outcomes.iter().enumerate() {
+                if idx > 0 {
+                    println!();
+                }
+
+                match outcome {
+                    RunOutcome::Done(output) => print_output(*day, output, answers),
+                    RunOutcome::Panicked(msg) => println!("Day {:02}\nPanicked: {}", day, msg),
+                    RunOutcome::InvalidInput(e) => println!("Day {:02}\nInvalid input: {}", day, e),
+                    RunOutcome::TimedOut => println!("Day {:02}\nTimed out", day),
+                }
+            }
+
+            println!();
+            print_run_summary(&outcomes, &missing, start.elapsed(), timeout);
+        }
+        OutputFormat::Json => print_json(&outcomes, &missing),
+    }
+
+    let all_done = outcomes.values().all(|o| matches!(o, RunOutcome::Done(_)));
+    if all_done && missing.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_bench(args: BenchArgs, year: u16) -> ExitCode {
+    if let Err(e) = args.input.check(&args.days) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+
+    let solvers = match args.params.resolve(year, &args.days) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let budget = match (args.budget, &config().bench.budget) {
+        (Some(budget), _) => budget,
+        (None, Some(budget)) => match parse_duration(budget) {
+            Ok(b) => b,
+            Err(e) => {
+                eprintln!("Invalid bench budget in the configuration: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        (None, None) => Duration::from_secs(3),
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let opts = BenchOptions {
+        warmup: args.warmup.or(config().bench.warmup).unwrap_or(3),
+        runs: args.runs,
+        budget,
+    };
+    let history_file = args.history.path(year);
+
+    for (idx, (day, (runner, params))) in solvers.into_iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+
+        let Some(input) = load_input(year, day, args.input.input.as_deref()) else {
+            status = ExitCode::FAILURE;
+            continue;
+        };
+        let result = match bench(runner, &input, &params, &opts) {
+            Ok(r) => r,
+            Err(e) => {
+                eprintln!("Day {:02}: invalid input: {}", day, e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        print_bench_result(day, &result);
+
+        if !args.no_save {
+            let records = Record::from_result(day, &result, &args.label);
+            if let Err(e) = history::append(&history_file, &records) {
+                eprintln!(
+                    "Unable to save results to {}: {}",
+                    history_file.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    status
+}
+
+fn run_compare(args: CompareArgs, year: u16) -> ExitCode {
+    let records = match history::load(&args.history.path(year)) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Unable to load benchmark history: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut regressions = 0;
+
+    for (idx, day) in args.days.0.enumerate() {
+        if idx > 0 {
+            println!();
+        }
+
+        let comparisons = history::compare(&records, day, args.baseline.as_deref());
+        print_comparisons(day, &comparisons, args.threshold);
+
+        regressions += comparisons
+            .iter()
+            .filter(|c| c.is_regression(args.threshold))
+            .count();
+    }
+
+    if regressions > 0 {
+        println!();
+        println!("{} regression(s) above {}%", regressions, args.threshold);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run_fetch(args: FetchArgs, year: u16) -> ExitCode {
+    // Only built for the first download, so that no session is needed when every input is there.
+    let mut client = None;
+    let mut status = ExitCode::SUCCESS;
+
+    for day in args.days.0 {
+        let dest = input_path(&input_file(year, day));
+
+        if !args.force && is_cached(&dest) {
+            println!("Day {:02}: {} already exists", day, dest.display());
+            continue;
+        }
+        let client = match client {
+            Some(ref c) => c,
+            None => match args.server.client() {
+                Ok(c) => client.insert(c),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+
+        match fetch_input(client, year, day, &dest, args.force) {
+            Ok(Fetched::Cached) => println!("Day {:02}: {} already exists", day, dest.display()),
+            Ok(Fetched::Downloaded(len)) => {
+                println!("Day {:02}: saved {} bytes to {}", day, len, dest.display())
+            }
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day, e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    status
+}
+
+fn run_verify(args: VerifyArgs, year: u16) -> ExitCode {
+    let answers = match Answers::load(&args.answers, year) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solvers = match args.params.resolve(year, &args.days) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let (mut ok, mut wrong, mut missing) = (0, 0, 0);
+
+    for (day, (runner, params)) in solvers {
+        let Some(input) = load_input(year, day, None) else {
+            status = ExitCode::FAILURE;
+            continue;
+        };
+        let output = match runner(&input, &params) {
+            Ok(o) => o,
+            Err(e) => {
+                eprintln!("Day {:02}: invalid input: {}", day, e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for (part, answer) in [(1, &output.part1), (2, &output.part2)] {
+            let answer = answer.to_string();
+            let verdict = answers.check(day, part, &answer);
+            print_verdict(day, part, &answer, &verdict);
+
+            match verdict {
+                Verdict::Ok => ok += 1,
+                Verdict::Wrong { .. } => wrong += 1,
+                Verdict::Missing => missing += 1,
+            }
+        }
+    }
+
+    println!();
+    println!("{} OK, {} WRONG, {} MISSING", ok, wrong, missing);
+
+    if wrong > 0 {
+        status = ExitCode::FAILURE;
+    }
+
+    status
+}
+
+fn run_submit(args: SubmitArgs, year: u16) -> ExitCode {
+    let (runner, params) = match args.params.resolve(year, &Days(args.day..=args.day)) {
+        Ok(mut s) => s.remove(&args.day).expect("the day is resolved"),
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(input) = load_input(year, args.day, args.input.input.as_deref()) else {
+        return ExitCode::FAILURE;
+    };
+    let output = match runner(&input, &params) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("Day {:02}: invalid input: {}", args.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = match args.part {
+        1 => output.part1,
+        _ => output.part2,
+    };
+
+    println!("Day {:02} part {}: {}", args.day, args.part, answer);
+
+    if answer == Answer::Unsolved {
+        eprintln!("Not submitting: the part isn't solved yet");
+        return ExitCode::FAILURE;
+    }
+    let answer = answer.to_string();
+
+    let log_file = args.log.unwrap_or_else(|| cache_dir().join(GUESS_LOG_FILE));
+    let log = match GuessLog::load(&log_file) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = log.check(year, args.day, args.part, &answer) {
+        eprintln!("Not submitting: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    let guess = match args
+        .server
+        .client()
+        .and_then(|c| submit(&c, year, args.day, args.part, &answer).map_err(|e| e.to_string()))
+    {
+        Ok(g) => g,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = GuessLog::append(&log_file, &guess) {
+        eprintln!(
+            "Unable to record the guess in {}: {}",
+            log_file.display(),
+            e
+        );
+    }
+
+    println!("{}", guess.outcome);
+
+    match guess.outcome {
+        Outcome::Correct => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn run_new(args: NewArgs, year: u16) -> ExitCode {
+    match new_day(Path::new(WORKSPACE_ROOT), year, args.day) {
+        Ok(changed) => {
+            for path in changed {
+                println!("{}", path);
+            }
+
+            let registered = days::days(year).map_or(0, |d| d.len());
+            if registered < args.day as usize {
+                println!(
+                    "Register `Day::new::<{}>()` for {} in aoc/src/days.rs to run it",
+                    DayCrate::new(year, args.day).solution(),
+                    year
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_status(args: StatusArgs, year: u16) -> ExitCode {
+    let answers = Answers::load(&args.answers, year).unwrap_or_else(|e| {
+        eprintln!("Ignoring known answers: {}", e);
+        Answers::default()
+    });
+    let history_file = args.history.path(year);
+    let history = if history_file.exists() {
+        history::load(&history_file).unwrap_or_else(|e| {
+            eprintln!("Ignoring benchmark history: {}", e);
+            Vec::new()
+        })
+    } else {
+        Vec::new()
+    };
+
+    let statuses = (1..=LAST_DAY)
+        .map(|day| {
+            let has_input = input_path(&input_file(year, day)).exists();
+
+            day_status(
+                Path::new(WORKSPACE_ROOT),
+                &DayCrate::new(year, day),
+                has_input,
+                !args.skip_tests,
+                &history,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    print_status(&statuses, &answers, args.format);
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("7", 7..=7)]
+    #[case("3..9", 3..=9)]
+    #[case("3..=9", 3..=9)]
+    #[case("all", 1..=25)]
+    fn test_parse_days(#[case] input: &str, #[case] expected: RangeInclusive<u8>) {
+        assert_eq!(input.parse::<Days>(), Ok(Days(expected)));
+    }
+
+    #[rstest]
+    #[case("0")]
+    #[case("26")]
+    #[case("9..3")]
+    #[case("foo")]
+    fn test_parse_days_invalid(#[case] input: &str) {
+        assert!(input.parse::<Days>().is_err());
+    }
+    #[rstest]
+    #[case("width=11", ("width", 11))]
+    #[case(" offset = 0 ", ("offset", 0))]
+    fn test_parse_param(#[case] input: &str, #[case] expected: (&str, i64)) {
+        assert_eq!(parse_param(input), Ok((expected.0.to_owned(), expected.1)));
+    }
+
+    #[rstest]
+    #[case("width")]
+    #[case("width=eleven")]
+    fn test_parse_param_invalid(#[case] input: &str) {
+        assert!(parse_param(input).is_err());
+    }
+
+    #[rstest]
+    fn test_resolve_params() {
+        let path = testing::temp_path("aoc.toml");
+        std::fs::write(&path, "[params.2024.day14]\nwidth = 11\nheight = 50\n").unwrap();
+        let config = Config::load(&path).unwrap();
+        let args = ParamArgs {
+            params: vec![("height".to_owned(), 7)],
+        };
+
+        let solvers = args.resolve_with(2024, &Days(14..=14), &config).unwrap();
+
+        assert_eq!(solvers[&14].1.to_string(), "width=11, height=7, ticks=100");
+        assert!(args.resolve_with(2024, &Days(13..=14), &config).is_err());
+        assert!(args.resolve_with(2023, &Days(14..=14), &config).is_err());
+    }
+
+    #[rstest]
+    #[case::zero_width(14, "width", 0)]
+    #[case::negative_blinks(11, "blinks1", -3)]
+    fn test_resolve_params_out_of_range(#[case] day: u8, #[case] name: &str, #[case] value: i64) {
+        let args = ParamArgs {
+            params: vec![(name.to_owned(), value)],
+        };
+
+        let err = args
+            .resolve_with(2024, &Days(day..=day), &Config::default())
+            .err()
+            .unwrap();
+
+        assert!(
+            err.starts_with(&format!("Day {}: invalid value {}", day, value)),
+            "{}",
+            err
+        );
+    }
+}
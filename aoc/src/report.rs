@@ -0,0 +1,439 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use aoc_common::{format_bytes, format_duration, AllocStats, Answer};
+use serde_json::{json, Value};
+
+use crate::answers::{Answers, Verdict};
+use crate::bench::{BenchResult, Stats};
+use crate::history::Comparison;
+use crate::runner::{Allocations, Output, RunOutcome, Timings};
+use crate::status::{DayStatus, TestResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable answers and timings.
+    Text,
+    /// A JSON array with an object per day, for scripts.
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum TableFormat {
+    /// Aligned columns for the terminal.
+    Terminal,
+    /// A Markdown table, e.g. for the README.
+    Markdown,
+}
+
+pub fn print_output(day: u8, output: &Output, answers: &Answers) {
+    println!("Day {:02}", day);
+    for (part, answer) in [(1, &output.part1), (2, &output.part2)] {
+        match answers.check(day, part, &answer.to_string()) {
+            Verdict::Wrong { expected } => {
+                println!("Part {}: {} (WRONG, expected {})", part, answer, expected)
+            }
+            _ => println!("Part {}: {}", part, answer),
+        }
+    }
+    println!();
+    match &output.allocations {
+        Some(allocations) => print_timings_and_allocations(&output.timings, allocations),
+        None => print_timings(&output.timings),
+    }
+}
+
+fn print_timings(timings: &Timings) {
+    let rows = [
+        ("Parse", timings.parse),
+        ("Part 1", timings.part1),
+        ("Part 2", timings.part2),
+        ("Total", timings.total()),
+    ];
+
+    println!("| {:<6} | {:>12} |", "Phase", "Duration");
+    println!("|{:-<8}|{:->14}|", "", "");
+    for (phase, duration) in rows {
+        println!(
+            "| {:<6} | {:>12} |",
+            phase,
+            format_duration(duration.as_nanos())
+        );
+    }
+}
+
+fn print_timings_and_allocations(timings: &Timings, allocations: &Allocations) {
+    let rows = [
+        ("Parse", timings.parse, allocations.parse),
+        ("Part 1", timings.part1, allocations.part1),
+        ("Part 2", timings.part2, allocations.part2),
+        ("Total", timings.total(), allocations.total()),
+    ];
+
+    println!(
+        "| {:<6} | {:>12} | {:>10} | {:>12} | {:>12} |",
+        "Phase", "Duration", "Allocs", "Allocated", "Peak"
+    );
+    println!(
+        "|{:-<8}|{:->14}|{:->12}|{:->14}|{:->14}|",
+        "", "", "", "", ""
+    );
+    for (phase, duration, stats) in rows {
+        println!(
+            "| {:<6} | {:>12} | {:>10} | {:>12} | {:>12} |",
+            phase,
+            format_duration(duration.as_nanos()),
+            stats.count,
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak),
+        );
+    }
+}
+
+pub fn print_run_summary(
+    outcomes: &BTreeMap<u8, RunOutcome>,
+    missing: &[u8],
+    elapsed: Duration,
+    timeout: Duration,
+) {
+    let total = outcomes
+        .values()
+        .filter_map(|o| match o {
+            RunOutcome::Done(output) => Some(output.timings.total()),
+            _ => None,
+        })
+        .sum::<Duration>();
+    let count = |f: fn(&RunOutcome) -> bool| outcomes.values().filter(|o| f(o)).count();
+
+    println!(
+        "{} day(s) in {} ({} of solving)",
+        outcomes.len() + missing.len(),
+        format_duration(elapsed.as_nanos()),
+        format_duration(total.as_nanos())
+    );
+    println!(
+        "{} done, {} failed, {} timed out, {} without input",
+        count(|o| matches!(o, RunOutcome::Done(_))),
+        count(|o| matches!(o, RunOutcome::Panicked(_) | RunOutcome::InvalidInput(_))),
+        count(|o| matches!(o, RunOutcome::TimedOut)),
+        missing.len()
+    );
+
+    for (day, outcome) in outcomes {
+        match outcome {
+            RunOutcome::Done(_) => {}
+            RunOutcome::Panicked(msg) => println!("Day {:02}: panicked: {}", day, msg),
+            RunOutcome::InvalidInput(e) => println!("Day {:02}: invalid input: {}", day, e),
+            RunOutcome::TimedOut => println!(
+                "Day {:02}: timed out after {}",
+                day,
+                format_duration(timeout.as_nanos())
+            ),
+        }
+    }
+    for day in missing {
+        println!("Day {:02}: no input", day);
+    }
+}
+
+/// Print the outcome of each day as a JSON array. Days that didn't produce answers have an
+/// `error` instead of answers and timings.
+pub fn print_json(outcomes: &BTreeMap<u8, RunOutcome>, missing: &[u8]) {
+    let mut days = outcomes
+        .iter()
+        .map(|(&day, outcome)| outcome_json(day, outcome))
+        .chain(
+            missing
+                .iter()
+                .map(|&day| json!({ "day": day, "error": "no input" })),
+        )
+        .collect::<Vec<Value>>();
+    days.sort_by_key(|d| d["day"].as_u64());
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&days).expect("JSON values are serializable")
+    );
+}
+
+fn outcome_json(day: u8, outcome: &RunOutcome) -> Value {
+    match outcome {
+        RunOutcome::Done(output) => {
+            let mut value = json!({
+                "day": day,
+                "part1": answer_json(&output.part1),
+                "part2": answer_json(&output.part2),
+                "timings": {
+                    "parse_ns": output.timings.parse.as_nanos() as u64,
+                    "part1_ns": output.timings.part1.as_nanos() as u64,
+                    "part2_ns": output.timings.part2.as_nanos() as u64,
+                    "total_ns": output.timings.total().as_nanos() as u64,
+                },
+            });
+            if let Some(allocations) = &output.allocations {
+                value["allocations"] = json!({
+                    "parse": alloc_stats_json(&allocations.parse),
+                    "part1": alloc_stats_json(&allocations.part1),
+                    "part2": alloc_stats_json(&allocations.part2),
+                    "total": alloc_stats_json(&allocations.total()),
+                });
+            }
+            value
+        }
+        RunOutcome::Panicked(msg) => json!({ "day": day, "error": format!("panicked: {}", msg) }),
+        RunOutcome::InvalidInput(e) => {
+            json!({ "day": day, "error": format!("invalid input: {}", e) })
+        }
+        RunOutcome::TimedOut => json!({ "day": day, "error": "timed out" }),
+    }
+}
+
+fn alloc_stats_json(stats: &AllocStats) -> Value {
+    json!({ "count": stats.count, "bytes": stats.bytes, "peak": stats.peak })
+}
+
+/// Integers are JSON numbers, except for big integers which are strings so that they don't lose
+/// precision. Unsolved parts are `null`.
+fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(i) => json!(i),
+        Answer::BigInt(i) => json!(i.to_string()),
+        Answer::Str(s) => json!(s),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+pub fn print_verdict(day: u8, part: u8, answer: &str, verdict: &Verdict) {
+    let prefix = format!("Day {:02} part {}: {:<7}", day, part, verdict);
+
+    match verdict {
+        Verdict::Ok => println!("{}", prefix.trim_end()),
+        Verdict::Wrong { expected } => println!("{} got {}, expected {}", prefix, answer, expected),
+        Verdict::Missing => println!("{} got {}", prefix, answer),
+    }
+}
+
+pub fn print_bench_result(day: u8, result: &BenchResult) {
+    let rows = [
+        ("Parse", &result.parse),
+        ("Part 1", &result.part1),
+        ("Part 2", &result.part2),
+        ("Total", &result.total),
+    ];
+
+    println!("Day {:02} ({} runs)", day, result.runs);
+    println!();
+    println!(
+        "| {:<6} | {:>12} | {:>12} | {:>12} | {:>12} |",
+        "Phase", "Min", "Median", "Mean", "Std dev"
+    );
+    println!(
+        "|{:-<8}|{:->14}|{:->14}|{:->14}|{:->14}|",
+        "", "", "", "", ""
+    );
+    for (phase, stats) in rows {
+        print_stats_row(phase, stats);
+    }
+}
+
+fn print_stats_row(phase: &str, stats: &Stats) {
+    println!(
+        "| {:<6} | {:>12} | {:>12} | {:>12} | {:>12} |",
+        phase,
+        format_duration(stats.min.as_nanos()),
+        format_duration(stats.median.as_nanos()),
+        format_duration(stats.mean.as_nanos()),
+        format_duration(stats.stddev.as_nanos()),
+    );
+}
+
+pub fn print_comparisons(day: u8, comparisons: &[Comparison], threshold: f64) {
+    let Some(first) = comparisons.first() else {
+        println!("Day {:02}: nothing to compare", day);
+        return;
+    };
+
+    println!(
+        "Day {:02} ({} vs {})",
+        day,
+        describe_run(first.after.commit.as_str(), first.after.label.as_str()),
+        describe_run(first.before.commit.as_str(), first.before.label.as_str()),
+    );
+    println!();
+    println!(
+        "| {:<6} | {:>12} | {:>12} | {:>8} |",
+        "Phase", "Before", "After", "Change"
+    );
+    println!("|{:-<8}|{:->14}|{:->14}|{:->10}|", "", "", "", "");
+
+    for c in comparisons {
+        println!(
+            "| {:<6} | {:>12} | {:>12} | {:>8} |{}",
+            c.phase,
+            format_duration(c.before.stats.median.as_nanos()),
+            format_duration(c.after.stats.median.as_nanos()),
+            c.change()
+                .map_or_else(|| "n/a".to_owned(), |c| format!("{:+.1}%", c)),
+            if c.is_regression(threshold) {
+                " REGRESSION"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+fn describe_run(commit: &str, label: &str) -> String {
+    if label.is_empty() {
+        commit.to_owned()
+    } else {
+        format!("{} [{}]", commit, label)
+    }
+}
+
+pub fn print_status(statuses: &[DayStatus], answers: &Answers, format: TableFormat) {
+    let header = [
+        "Day",
+        "Part 1",
+        "Part 2",
+        "Input",
+        "Full input tests",
+        "Last time",
+    ];
+    let rows = statuses
+        .iter()
+        .map(|s| {
+            let part = |idx: usize| if s.implemented[idx] { "done" } else { "TODO" };
+            let test = |res: TestResult| match res {
+                TestResult::Passed => "pass",
+                TestResult::Failed => "FAIL",
+                TestResult::NotRun => "-",
+            };
+
+            [
+                format!("{:02}", s.day),
+                part(0).to_owned(),
+                part(1).to_owned(),
+                if s.has_input { "yes" } else { "no" }.to_owned(),
+                format!("{} / {}", test(s.tests[0]), test(s.tests[1])),
+                s.last_time
+                    .map_or("-".to_owned(), |t| format_duration(t.as_nanos())),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let stars = statuses.iter().map(|s| s.stars(answers)).sum::<usize>();
+
+    match format {
+        TableFormat::Terminal => {
+            let widths = header.map(str::len);
+            let widths = rows.iter().fold(widths, |mut widths, row| {
+                for (w, cell) in widths.iter_mut().zip(row) {
+                    *w = (*w).max(cell.chars().count());
+                }
+                widths
+            });
+            let print_row = |cells: &[&str]| {
+                let line = cells
+                    .iter()
+                    .zip(widths)
+                    .map(|(cell, w)| format!("{:<w$}", cell, w = w))
+                    .collect::<Vec<_>>()
+                    .join("  ");
+                println!("{}", line.trim_end());
+            };
+
+            print_row(&header);
+            for row in &rows {
+                print_row(&row.each_ref().map(String::as_str));
+            }
+            println!();
+            println!("Stars: {}/{}", stars, statuses.len() * 2);
+        }
+        TableFormat::Markdown => {
+            println!("| {} |", header.join(" | "));
+            println!("|{}|", ["---"; 6].join("|"));
+            for row in &rows {
+                println!("| {} |", row.join(" | "));
+            }
+            println!();
+            println!("**Stars: {}/{}**", stars, statuses.len() * 2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::runner::{Allocations, Output, Timings};
+
+    #[rstest]
+    #[case(Answer::Int(42), json!(42))]
+    #[case(Answer::BigInt(i64::MAX as i128 + 1), json!("9223372036854775808"))]
+    #[case(Answer::Str("6,0,6".to_owned()), json!("6,0,6"))]
+    #[case(Answer::Unsolved, Value::Null)]
+    fn test_answer_json(#[case] answer: Answer, #[case] expected: Value) {
+        assert_eq!(answer_json(&answer), expected);
+    }
+
+    #[rstest]
+    fn test_outcome_json() {
+        let output = Output {
+            part1: Answer::Int(11),
+            part2: Answer::Unsolved,
+            timings: Timings {
+                parse: Duration::from_nanos(1),
+                part1: Duration::from_nanos(2),
+                part2: Duration::from_nanos(3),
+            },
+            allocations: None,
+        };
+
+        assert_eq!(
+            outcome_json(1, &RunOutcome::Done(output)),
+            json!({
+                "day": 1,
+                "part1": 11,
+                "part2": null,
+                "timings": { "parse_ns": 1, "part1_ns": 2, "part2_ns": 3, "total_ns": 6 },
+            })
+        );
+        assert_eq!(
+            outcome_json(
+                24,
+                &RunOutcome::Panicked("Found nothing to solve".to_owned())
+            ),
+            json!({ "day": 24, "error": "panicked: Found nothing to solve" })
+        );
+    }
+
+    #[rstest]
+    fn test_outcome_json_allocations() {
+        let stats = |count, bytes, peak| AllocStats { count, bytes, peak };
+        let output = Output {
+            part1: Answer::Int(11),
+            part2: Answer::Int(12),
+            timings: Timings {
+                parse: Duration::ZERO,
+                part1: Duration::ZERO,
+                part2: Duration::ZERO,
+            },
+            allocations: Some(Allocations {
+                parse: stats(2, 100, 80),
+                part1: stats(0, 0, 0),
+                part2: stats(3, 50, 20),
+            }),
+        };
+
+        assert_eq!(
+            outcome_json(1, &RunOutcome::Done(output))["allocations"],
+            json!({
+                "parse": { "count": 2, "bytes": 100, "peak": 80 },
+                "part1": { "count": 0, "bytes": 0, "peak": 0 },
+                "part2": { "count": 3, "bytes": 50, "peak": 20 },
+                "total": { "count": 5, "bytes": 150, "peak": 80 },
+            })
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::{measure, AllocStats, Answer, Param, Params, ParseError, PuzzleInput, Solution};
+use tracing::Span;
+
+/// The answers computed for a day, along with the time spent in each phase.
+pub struct Output {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+    /// What each phase allocated, when built with the `alloc-stats` feature.
+    pub allocations: Option<Allocations>,
+}
+
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+pub struct Allocations {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl Allocations {
+    pub fn total(&self) -> AllocStats {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// A day's solver, along with the parameters of its puzzle.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub run: Runner,
+    /// Named parameters and their defaults, see [`Solution::PARAMS`].
+    pub params: &'static [Param],
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Day {
+            run: run::<S>,
+            params: S::PARAMS,
+        }
+    }
+}
+
+pub type Runner = fn(&PuzzleInput, &Params) -> Result<Output, ParseError>;
+
+pub fn run<S: Solution>(input: &PuzzleInput, params: &Params) -> Result<Output, ParseError> {
+    let (parsed, parse, parse_allocs) = measured(tracing::debug_span!("parse"), || {
+        S::parse_with_params(input, params)
+    });
+    let parsed = parsed?;
+    let (part1, part1_time, part1_allocs) =
+        measured(tracing::debug_span!("part1"), || S::part1(&parsed));
+    let (part2, part2_time, part2_allocs) =
+        measured(tracing::debug_span!("part2"), || S::part2(&parsed));
+
+    Ok(Output {
+        part1,
+        part2,
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+        allocations: cfg!(feature = "alloc-stats").then_some(Allocations {
+            parse: parse_allocs,
+            part1: part1_allocs,
+            part2: part2_allocs,
+        }),
+    })
+}
+
+/// Run a phase within `span`, measuring its duration and what it allocated.
+fn measured<T>(span: Span, f: impl FnOnce() -> T) -> (T, Duration, AllocStats) {
+    let _span = span.entered();
+    let ((res, elapsed), allocs) = measure(|| {
+        let start = Instant::now();
+        let res = f();
+
+        (res, start.elapsed())
+    });
+
+    (res, elapsed, allocs)
+}
+
+/// How a day run in isolation by [`run_all`] ended.
+pub enum RunOutcome {
+    Done(Output),
+    /// The day's input couldn't be parsed.
+    InvalidInput(ParseError),
+    /// The day panicked, with the panic message.
+    Panicked(String),
+    /// The day didn't finish within the timeout. Its thread is left running in the background.
+    TimedOut,
+}
+
+/// Run days concurrently, each on its own thread, with at most `jobs` of them running at once.
+/// A day that panics or runs for longer than `timeout` doesn't affect the others.
+///
+/// A thread can't be stopped, so a day that times out keeps running in the background but no
+/// longer counts towards `jobs`: the next day is started in its place, rather than waiting for a
+/// day that may never end.
+///
+/// `on_done` is called as soon as a day ends, in the order they end.
+pub fn run_all(
+    days: Vec<(u8, Runner, Params, PuzzleInput)>,
+    jobs: usize,
+    timeout: Duration,
+    mut on_done: impl FnMut(u8, RunOutcome),
+) {
+    let (tx, rx) = mpsc::channel();
+    let mut pending = days.into_iter();
+    let mut running = BTreeMap::new();
+
+    loop {
+        while running.len() < jobs.max(1) {
+            let Some((day, runner, params, input)) = pending.next() else {
+                break;
+            };
+            let tx = tx.clone();
+
+            running.insert(day, Instant::now() + timeout);
+            thread::Builder::new()
+                .name(format!("day{:02}", day))
+                .spawn(move || {
+                    let outcome = match catch_unwind(AssertUnwindSafe(|| runner(&input, &params))) {
+                        Ok(Ok(output)) => RunOutcome::Done(output),
+                        Ok(Err(e)) => RunOutcome::InvalidInput(e),
+                        Err(payload) => RunOutcome::Panicked(panic_message(payload.as_ref())),
+                    };
+                    // The receiver is gone if the other days are done and this one timed out.
+                    let _ = tx.send((day, outcome));
+                })
+                .expect("unable to spawn a thread");
+        }
+
+        let Some(deadline) = running.values().min().copied() else {
+            break;
+        };
+
+        match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            // A day that already timed out isn't running anymore.
+            Ok((day, outcome)) => {
+                if running.remove(&day).is_some() {
+                    on_done(day, outcome);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let expired = running
+                    .iter()
+                    .filter(|(_, &deadline)| deadline <= now)
+                    .map(|(&day, _)| day)
+                    .collect::<Vec<u8>>();
+
+                for day in expired {
+                    running.remove(&day);
+                    on_done(day, RunOutcome::TimedOut);
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("a sender is kept until the end"),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ok(input: &PuzzleInput, _params: &Params) -> Result<Output, ParseError> {
+        Ok(Output {
+            part1: input.len().into(),
+            part2: Answer::Unsolved,
+            timings: Timings {
+                parse: Duration::ZERO,
+                part1: Duration::ZERO,
+                part2: Duration::ZERO,
+            },
+            allocations: None,
+        })
+    }
+
+    fn invalid(_input: &PuzzleInput, _params: &Params) -> Result<Output, ParseError> {
+        Err(ParseError::input("a blank line"))
+    }
+
+    fn panics(_input: &PuzzleInput, _params: &Params) -> Result<Output, ParseError> {
+        panic!("Found nothing to solve");
+    }
+
+    fn hangs(_input: &PuzzleInput, _params: &Params) -> Result<Output, ParseError> {
+        loop {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn describe(outcome: RunOutcome) -> String {
+        match outcome {
+            RunOutcome::Done(output) => format!("done {}", output.part1),
+            RunOutcome::InvalidInput(e) => format!("invalid {}", e),
+            RunOutcome::Panicked(msg) => format!("panicked {}", msg),
+            RunOutcome::TimedOut => "timed out".to_owned(),
+        }
+    }
+
+    #[rstest]
+    fn test_run_all_after_timeout() {
+        let none = Params::new(&[]);
+        let empty = PuzzleInput::from("");
+        let days: Vec<(u8, Runner, Params, PuzzleInput)> = vec![
+            (5, hangs, none.clone(), empty.clone()),
+            (9, ok, none, empty),
+        ];
+        let mut outcomes = Vec::new();
+
+        run_all(days, 1, Duration::from_millis(100), |day, outcome| {
+            outcomes.push((day, describe(outcome)));
+        });
+
+        // Day 9 only starts once day 5 is given up on, even though day 5 never returns.
+        assert_eq!(
+            outcomes,
+            vec![(5, "timed out".to_owned()), (9, "done 0".to_owned())]
+        );
+    }
+
+    #[rstest]
+    #[case::one_job(1)]
+    #[case::all_at_once(4)]
+    fn test_run_all(#[case] jobs: usize) {
+        let none = Params::new(&[]);
+        let empty = PuzzleInput::from("");
+        let days: Vec<(u8, Runner, Params, PuzzleInput)> = vec![
+            (1, ok, none.clone(), PuzzleInput::from("a\na\na")),
+            (5, hangs, none.clone(), empty.clone()),
+            (7, panics, none.clone(), empty.clone()),
+            (8, invalid, none.clone(), empty.clone()),
+            (9, ok, none, empty),
+        ];
+        let mut outcomes = BTreeMap::new();
+
+        run_all(days, jobs, Duration::from_secs(1), |day, outcome| {
+            outcomes.insert(day, describe(outcome));
+        });
+
+        assert_eq!(
+            outcomes.into_iter().collect::<Vec<_>>(),
+            vec![
+                (1, "done 3".to_owned()),
+                (5, "timed out".to_owned()),
+                (7, "panicked Found nothing to solve".to_owned()),
+                (8, "invalid expected a blank line".to_owned()),
+                (9, "done 0".to_owned()),
+            ]
+        );
+    }
+}
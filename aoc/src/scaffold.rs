@@ -0,0 +1,288 @@
+use std::fs;
+use std::path::Path;
+
+/// Root of the workspace new days are created in.
+pub const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.tmpl");
+
+/// The days of this year predate the support of several years: their crates are at the root of
+/// the workspace, and named after the day only.
+const FIRST_YEAR: u16 = 2024;
+
+/// Location of the crate of a day in the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayCrate {
+    pub year: u16,
+    pub day: u8,
+    /// Directory of the crate, relative to the root of the workspace, e.g. `2025/day07`.
+    pub dir: String,
+    /// Name of the package, e.g. `y2025-day07`.
+    pub package: String,
+}
+
+impl DayCrate {
+    pub fn new(year: u16, day: u8) -> Self {
+        let name = format!("day{:02}", day);
+        let (dir, package) = if year == FIRST_YEAR {
+            (name.clone(), name)
+        } else {
+            (format!("{}/{}", year, name), format!("y{}-{}", year, name))
+        };
+
+        DayCrate {
+            year,
+            day,
+            dir,
+            package,
+        }
+    }
+
+    /// Path of the solution type, as registered in `aoc/src/days.rs`.
+    pub fn solution(&self) -> String {
+        format!("{}::Day{:02}", self.package.replace('-', "_"), self.day)
+    }
+}
+
+/// Adds a day to the content of a manifest, or returns `None` if it can't.
+type Register = fn(&str, &DayCrate) -> Option<String>;
+
+/// Create the crate of `day` of `year` in the workspace at `root` from the template, along with an
+/// empty `example.txt` fixture, and register it in the workspace members and the dependencies of
+/// the `aoc` binary.
+///
+/// Returns the files created or modified.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<String>, String> {
+    let krate = DayCrate::new(year, day);
+    let dir = root.join(&krate.dir);
+
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let mut changed = Vec::new();
+    let mut write = |path: &Path, content: &str| {
+        fs::write(path, content)
+            .map_err(|e| format!("unable to write {}: {}", path.display(), e))?;
+        changed.push(
+            path.strip_prefix(root)
+                .unwrap_or(path)
+                .display()
+                .to_string(),
+        );
+
+        Ok::<_, String>(())
+    };
+
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("unable to create {}: {}", dir.display(), e))?;
+    write(&dir.join("Cargo.toml"), &render(CARGO_TEMPLATE, &krate))?;
+    write(&dir.join("src/lib.rs"), &render(LIB_TEMPLATE, &krate))?;
+    write(&dir.join("example.txt"), "")?;
+
+    let registrations: [(&Path, Register); 2] = [
+        (Path::new("Cargo.toml"), add_member),
+        (Path::new("aoc/Cargo.toml"), add_dependency),
+    ];
+    for (manifest, register) in registrations {
+        let path = root.join(manifest);
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+
+        match register(&content, &krate) {
+            Some(updated) => write(&path, &updated)?,
+            None if content.contains(&krate.package) => {}
+            None => {
+                return Err(format!(
+                    "unable to register {} in {}",
+                    krate.package,
+                    path.display()
+                ))
+            }
+        }
+    }
+
+    Ok(changed)
+}
+
+fn render(template: &str, krate: &DayCrate) -> String {
+    let root = "../".repeat(krate.dir.split('/').count());
+
+    template
+        .replace("{{day}}", &format!("{:02}", krate.day))
+        .replace("{{year}}", &krate.year.to_string())
+        .replace("{{package}}", &krate.package)
+        .replace("{{root}}/", &root)
+}
+
+/// Add a day to the members of a workspace manifest, keeping the days sorted. Returns `None` if
+/// it's already there.
+fn add_member(manifest: &str, krate: &DayCrate) -> Option<String> {
+    let entry = format!("    \"{}\",", krate.dir);
+
+    insert_sorted_line(manifest, &entry, |l| {
+        let l = l.trim();
+        l.ends_with("\",") && (l.starts_with("\"day") || l.starts_with("\"2"))
+    })
+}
+
+/// Add a day as a path dependency of the `aoc` binary's manifest, keeping the days sorted.
+/// Returns `None` if it's already there.
+fn add_dependency(manifest: &str, krate: &DayCrate) -> Option<String> {
+    let entry = format!("{} = {{ path = \"../{}\" }}", krate.package, krate.dir);
+
+    insert_sorted_line(manifest, &entry, |l| {
+        (l.starts_with("day") || l.starts_with('y')) && l.contains("path = ")
+    })
+}
+
+/// Insert `entry` among the lines matching `is_day`, which are expected to be contiguous and
+/// sorted. Returns `None` if `entry` is already there, or if there's no day to insert it next to.
+fn insert_sorted_line(content: &str, entry: &str, is_day: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines = content.lines().collect::<Vec<&str>>();
+
+    if lines.contains(&entry) {
+        return None;
+    }
+
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| is_day(l))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<usize>>();
+    let pos = match days.iter().find(|&&idx| lines[idx] > entry) {
+        Some(&idx) => idx,
+        None => days.last()? + 1,
+    };
+
+    lines.insert(pos, entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::testing::temp_path;
+
+    const WORKSPACE: &str = r#"[workspace]
+members = [
+    "aoc",
+    "aoc-common",
+    "day01",
+    "day03",
+]
+resolver = "2"
+"#;
+
+    const AOC: &str = r#"[dependencies]
+clap = "4.5.23"
+day01 = { path = "../day01" }
+day03 = { path = "../day03" }
+
+[dev-dependencies]
+rstest = "0.23.0"
+"#;
+
+    #[rstest]
+    fn test_add_member() {
+        let updated = add_member(WORKSPACE, &DayCrate::new(2024, 2)).unwrap();
+
+        assert!(updated.contains("    \"day01\",\n    \"day02\",\n    \"day03\",\n]"));
+        assert_eq!(add_member(&updated, &DayCrate::new(2024, 2)), None);
+    }
+
+    #[rstest]
+    fn test_add_member_last() {
+        let updated = add_member(WORKSPACE, &DayCrate::new(2024, 25)).unwrap();
+
+        assert!(updated.contains("    \"day03\",\n    \"day25\",\n]"));
+    }
+
+    #[rstest]
+    fn test_add_member_other_year() {
+        let updated = add_member(WORKSPACE, &DayCrate::new(2025, 1)).unwrap();
+        let updated = add_member(&updated, &DayCrate::new(2025, 2)).unwrap();
+
+        assert!(updated.contains(
+            "    \"aoc-common\",\n    \"2025/day01\",\n    \"2025/day02\",\n    \"day01\",\n"
+        ));
+    }
+
+    #[rstest]
+    fn test_add_dependency() {
+        let updated = add_dependency(AOC, &DayCrate::new(2024, 2)).unwrap();
+
+        assert!(updated.contains(
+            "day01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\nday03 = { path = \"../day03\" }\n"
+        ));
+        assert_eq!(add_dependency(&updated, &DayCrate::new(2024, 2)), None);
+
+        let updated = add_dependency(&updated, &DayCrate::new(2025, 1)).unwrap();
+
+        assert!(updated.contains(
+            "day03 = { path = \"../day03\" }\ny2025-day01 = { path = \"../2025/day01\" }\n"
+        ));
+    }
+
+    #[rstest]
+    fn test_render() {
+        let krate = DayCrate::new(2024, 7);
+        let lib = render(LIB_TEMPLATE, &krate);
+        let manifest = render(CARGO_TEMPLATE, &krate);
+
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("get_input(\"2024/day07.txt\")"));
+        assert!(!lib.contains("{{"));
+        assert!(manifest.contains("name = \"day07\""));
+        assert!(manifest.contains("path = \"../aoc-common\""));
+    }
+
+    #[rstest]
+    fn test_render_other_year() {
+        let krate = DayCrate::new(2025, 7);
+        let manifest = render(CARGO_TEMPLATE, &krate);
+
+        assert_eq!(krate.dir, "2025/day07");
+        assert_eq!(krate.solution(), "y2025_day07::Day07");
+        assert!(manifest.contains("name = \"y2025-day07\""));
+        assert!(manifest.contains("path = \"../../aoc-common\""));
+        assert!(render(LIB_TEMPLATE, &krate).contains("get_input(\"2025/day07.txt\")"));
+    }
+
+    #[rstest]
+    fn test_new_day() {
+        let root = temp_path("workspace");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), AOC).unwrap();
+
+        let changed = new_day(&root, 2024, 2).unwrap();
+
+        assert_eq!(
+            changed,
+            vec![
+                "day02/Cargo.toml",
+                "day02/src/lib.rs",
+                "day02/example.txt",
+                "Cargo.toml",
+                "aoc/Cargo.toml",
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("day02/example.txt")).unwrap(),
+            ""
+        );
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"day02\""));
+        assert!(new_day(&root, 2024, 2)
+            .unwrap_err()
+            .contains("already exists"));
+    }
+}
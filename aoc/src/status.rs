@@ -0,0 +1,193 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::answers::Answers;
+use crate::history::Record;
+use crate::scaffold::DayCrate;
+
+/// Progress of a day, as shown by the `status` command.
+#[derive(Debug, PartialEq)]
+pub struct DayStatus {
+    pub day: u8,
+    /// Whether each part has an actual solution rather than the template's placeholder.
+    pub implemented: [bool; 2],
+    pub has_input: bool,
+    /// Result of the `test_pN_full_input` test of each part.
+    pub tests: [TestResult; 2],
+    /// Median total time of the latest benchmark of the day.
+    pub last_time: Option<Duration>,
+}
+
+impl DayStatus {
+    /// Stars earned by the day: parts that are implemented and whose answer was accepted, i.e.
+    /// registered in the known answers.
+    pub fn stars(&self, answers: &Answers) -> usize {
+        (1..=2)
+            .filter(|&part| {
+                self.implemented[part as usize - 1] && answers.get(self.day, part).is_some()
+            })
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestResult {
+    Passed,
+    Failed,
+    NotRun,
+}
+
+/// Gather the status of a day from the sources of the workspace at `root`. The full input tests
+/// are only run if `run_tests` is set and the input is present.
+pub fn day_status(
+    root: &Path,
+    krate: &DayCrate,
+    has_input: bool,
+    run_tests: bool,
+    history: &[Record],
+) -> DayStatus {
+    let day = krate.day;
+    let implemented = fs::read_to_string(root.join(&krate.dir).join("src/lib.rs"))
+        .map(|s| implemented_parts(&s))
+        .unwrap_or_default();
+    let tests = if run_tests && has_input {
+        run_full_input_tests(root, &krate.package)
+    } else {
+        [TestResult::NotRun; 2]
+    };
+
+    DayStatus {
+        day,
+        implemented,
+        has_input,
+        tests,
+        last_time: last_time(history, day),
+    }
+}
+
+/// Find out which parts of a day are implemented. The template and the days left unfinished
+/// return `Answer::Unsolved` from `part1` or `part2`.
+fn implemented_parts(source: &str) -> [bool; 2] {
+    [1, 2].map(|part| {
+        let signature = format!("fn part{}(", part);
+        let Some(start) = source.find(&signature) else {
+            return false;
+        };
+        let body = &source[start..];
+        let Some(body) = body.find('{').map(|idx| &body[idx + 1..]) else {
+            return false;
+        };
+        let body = body.split("\n    }").next().unwrap_or(body);
+
+        body.trim() != "Answer::Unsolved"
+    })
+}
+
+fn run_full_input_tests(root: &Path, package: &str) -> [TestResult; 2] {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let output = Command::new(cargo)
+        .args([
+            "test",
+            "--release",
+            "--lib",
+            "-p",
+            package,
+            "--",
+            "full_input",
+        ])
+        .current_dir(root)
+        .stderr(Stdio::null())
+        .output();
+
+    match output {
+        Ok(o) => parse_test_output(&String::from_utf8_lossy(&o.stdout)),
+        Err(_) => [TestResult::NotRun; 2],
+    }
+}
+
+/// Extract the results of the full input tests from the output of `cargo test`.
+fn parse_test_output(stdout: &str) -> [TestResult; 2] {
+    [1, 2].map(|part| {
+        let prefix = format!("test tests::test_p{}_full_input ... ", part);
+
+        stdout
+            .lines()
+            .find_map(|l| l.strip_prefix(&prefix))
+            .map_or(TestResult::NotRun, |res| match res.trim() {
+                "ok" => TestResult::Passed,
+                "FAILED" => TestResult::Failed,
+                _ => TestResult::NotRun,
+            })
+    })
+}
+
+fn last_time(history: &[Record], day: u8) -> Option<Duration> {
+    history
+        .iter()
+        .filter(|r| r.day == day && r.phase == "total")
+        .max_by_key(|r| r.timestamp)
+        .map(|r| r.stats.median)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::bench::Stats;
+
+    #[rstest]
+    #[case::solved(include_str!("../../day03/src/lib.rs"), [true, true])]
+    #[case::part2_placeholder(include_str!("../../day15/src/lib.rs"), [true, false])]
+    #[case::stub(include_str!("../../day10/src/lib.rs"), [false, false])]
+    #[case::template(include_str!("../templates/day/lib.rs.tmpl"), [false, false])]
+    fn test_implemented_parts(#[case] source: &str, #[case] expected: [bool; 2]) {
+        assert_eq!(implemented_parts(source), expected);
+    }
+
+    #[rstest]
+    fn test_parse_test_output() {
+        let stdout = "
+running 2 tests
+test tests::test_p2_full_input ... FAILED
+test tests::test_p1_full_input ... ok
+
+failures:
+";
+
+        assert_eq!(
+            parse_test_output(stdout),
+            [TestResult::Passed, TestResult::Failed]
+        );
+        assert_eq!(parse_test_output(""), [TestResult::NotRun; 2]);
+    }
+
+    #[rstest]
+    fn test_last_time() {
+        let record = |timestamp, day, phase: &str, median| Record {
+            timestamp,
+            commit: "abc1234".to_owned(),
+            label: String::new(),
+            day,
+            phase: phase.to_owned(),
+            runs: 10,
+            stats: Stats {
+                min: Duration::ZERO,
+                median: Duration::from_millis(median),
+                mean: Duration::ZERO,
+                stddev: Duration::ZERO,
+            },
+        };
+        let history = [
+            record(2, 5, "total", 20),
+            record(3, 5, "part1", 1),
+            record(1, 5, "total", 30),
+            record(4, 6, "total", 40),
+        ];
+
+        assert_eq!(last_time(&history, 5), Some(Duration::from_millis(20)));
+        assert_eq!(last_time(&history, 7), None);
+    }
+}
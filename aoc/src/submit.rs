@@ -0,0 +1,425 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::fetch::{Client, FetchError};
+
+/// Name of the file recording every answer submitted, in the cache directory.
+pub const GUESS_LOG_FILE: &str = "guesses.log";
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without any hint.
+    Wrong,
+    /// Submitted too soon after a previous answer; the answer wasn't checked.
+    Wait(Duration),
+    /// The part was already solved, or the previous one isn't; the answer wasn't checked.
+    AlreadySolved,
+    /// The response couldn't be understood.
+    Unknown,
+}
+
+impl Outcome {
+    /// Parse the page returned after submitting an answer.
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Outcome::Correct
+        } else if body.contains("That's not the right answer") {
+            if body.contains("too high") {
+                Outcome::TooHigh
+            } else if body.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if body.contains("You gave an answer too recently") {
+            Outcome::Wait(parse_wait(body).unwrap_or_default())
+        } else if body.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    fn to_log(&self) -> String {
+        match self {
+            Outcome::Correct => "correct".to_owned(),
+            Outcome::TooHigh => "too-high".to_owned(),
+            Outcome::TooLow => "too-low".to_owned(),
+            Outcome::Wrong => "wrong".to_owned(),
+            Outcome::Wait(d) => format!("wait-{}", d.as_secs()),
+            Outcome::AlreadySolved => "already-solved".to_owned(),
+            Outcome::Unknown => "unknown".to_owned(),
+        }
+    }
+
+    /// Whether the answer was actually checked by the server.
+    fn is_evaluated(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+        )
+    }
+}
+
+/// Parse the delay in "You have 1m 30s left to wait".
+fn parse_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+
+    body[start..end]
+        .split_whitespace()
+        .map(|t| {
+            let (n, unit) = [("h", 3600), ("m", 60), ("s", 1)]
+                .into_iter()
+                .find_map(|(suffix, secs)| Some((t.strip_suffix(suffix)?, secs)))?;
+
+            Some(n.parse::<u64>().ok()? * unit)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(d) => write!(f, "wait {}s", d.as_secs()),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "already-solved" => Ok(Outcome::AlreadySolved),
+            "unknown" => Ok(Outcome::Unknown),
+            _ => s
+                .strip_prefix("wait-")
+                .and_then(|n| n.parse().ok())
+                .map(|n| Outcome::Wait(Duration::from_secs(n)))
+                .ok_or_else(|| format!("invalid outcome {:?}", s)),
+        }
+    }
+}
+
+/// An answer submitted for a part of a puzzle, as recorded in the guess log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+impl Guess {
+    /// Fields are tab separated, the answer being last as it can contain anything but a newline.
+    fn to_log(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            self.outcome.to_log(),
+            self.answer
+        )
+    }
+
+    fn from_log(line: &str) -> Result<Self, String> {
+        let fields = line.splitn(6, '\t').collect::<Vec<&str>>();
+        if fields.len() != 6 {
+            return Err(format!("expected 6 fields, got {}", fields.len()));
+        }
+
+        let invalid = |idx: usize| format!("invalid value {:?}", fields[idx]);
+
+        Ok(Guess {
+            timestamp: fields[0].parse().map_err(|_| invalid(0))?,
+            year: fields[1].parse().map_err(|_| invalid(1))?,
+            day: fields[2].parse().map_err(|_| invalid(2))?,
+            part: fields[3].parse().map_err(|_| invalid(3))?,
+            outcome: fields[4].parse()?,
+            answer: fields[5].to_owned(),
+        })
+    }
+}
+
+/// Every answer submitted so far.
+#[derive(Debug, Default)]
+pub struct GuessLog {
+    guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    /// Load the log from `path`. A missing file is an empty log.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("unable to read {}: {}", path.display(), e)),
+        };
+
+        let guesses = content
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(idx, l)| {
+                Guess::from_log(l).map_err(|e| format!("{}:{}: {}", path.display(), idx + 1, e))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { guesses })
+    }
+
+    pub fn append(path: &Path, guess: &Guess) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        writeln!(file, "{}", guess.to_log())
+    }
+
+    /// Check that `answer` is worth submitting: the part isn't solved yet, the same answer wasn't
+    /// already rejected, and it's consistent with the "too high" and "too low" hints received.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        if answer.trim().is_empty() {
+            return Err("the answer is empty".to_owned());
+        }
+
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|g| g.year == year && g.day == day && g.part == part)
+            .filter(|g| g.outcome.is_evaluated());
+
+        for g in guesses {
+            if g.outcome == Outcome::Correct {
+                return Err(format!("already solved, the answer is {}", g.answer));
+            }
+            if g.answer == answer {
+                return Err(format!("{} was already submitted: {}", answer, g.outcome));
+            }
+
+            match (g.outcome.clone(), compare(answer, &g.answer)) {
+                (Outcome::TooHigh, Some(Ordering::Greater)) => {
+                    return Err(format!(
+                        "{} is higher than {}, which is too high",
+                        answer, g.answer
+                    ))
+                }
+                (Outcome::TooLow, Some(Ordering::Less)) => {
+                    return Err(format!(
+                        "{} is lower than {}, which is too low",
+                        answer, g.answer
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare two answers numerically, if they both are numbers.
+fn compare(a: &str, b: &str) -> Option<Ordering> {
+    let a = a.parse::<i128>().ok()?;
+    let b = b.parse::<i128>().ok()?;
+
+    Some(a.cmp(&b))
+}
+
+/// Submit `answer` for `part` of `day` and return how it was judged.
+pub fn submit(
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Guess, FetchError> {
+    let level = part.to_string();
+    let body = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    Ok(Guess {
+        timestamp,
+        year,
+        day,
+        part,
+        outcome: Outcome::from_response(&body),
+        answer: answer.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::testing::{serve_once, temp_path};
+
+    fn guess(part: u8, outcome: Outcome, answer: &str) -> Guess {
+        Guess {
+            timestamp: 1733029200,
+            year: 2024,
+            day: 1,
+            part,
+            outcome,
+            answer: answer.to_owned(),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "<p>That's the right answer!  You are one gold star closer.</p>",
+        Outcome::Correct
+    )]
+    #[case(
+        "<p>That's not the right answer; your answer is too high.  If you're stuck...</p>",
+        Outcome::TooHigh
+    )]
+    #[case(
+        "<p>That's not the right answer; your answer is too low.  If you're stuck...</p>",
+        Outcome::TooLow
+    )]
+    #[case(
+        "<p>That's not the right answer.  If you're stuck...</p>",
+        Outcome::Wrong
+    )]
+    #[case(
+        "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 30s left to wait.</p>",
+        Outcome::Wait(Duration::from_secs(90))
+    )]
+    #[case(
+        "<p>You gave an answer too recently.  You have 45s left to wait.</p>",
+        Outcome::Wait(Duration::from_secs(45))
+    )]
+    #[case(
+        "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+        Outcome::AlreadySolved
+    )]
+    #[case("<html>Something else</html>", Outcome::Unknown)]
+    fn test_outcome_from_response(#[case] body: &str, #[case] expected: Outcome) {
+        assert_eq!(Outcome::from_response(body), expected);
+    }
+
+    #[rstest]
+    #[case("You have 2h 1m 5s left to wait", Some(Duration::from_secs(7265)))]
+    #[case("You have 30 left to wait", None)]
+    #[case("You have 5µs left to wait", None)]
+    #[case("You have 3é left to wait", None)]
+    #[case("You have é left to wait", None)]
+    fn test_parse_wait(#[case] body: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(parse_wait(body), expected);
+    }
+
+    #[rstest]
+    #[case(Outcome::Correct)]
+    #[case(Outcome::TooHigh)]
+    #[case(Outcome::Wait(Duration::from_secs(42)))]
+    #[case(Outcome::AlreadySolved)]
+    fn test_guess_log_round_trip(#[case] outcome: Outcome) {
+        let g = guess(2, outcome, "6,0,6,3,0,2,3,1,6");
+
+        assert_eq!(Guess::from_log(&g.to_log()), Ok(g));
+    }
+
+    #[rstest]
+    fn test_guess_log_append_and_load() {
+        // The cache directory is created if needed.
+        let dir = temp_path("guess-cache");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("guesses.log");
+
+        GuessLog::append(&path, &guess(1, Outcome::TooLow, "10")).unwrap();
+        GuessLog::append(&path, &guess(1, Outcome::Correct, "12")).unwrap();
+        let log = GuessLog::load(&path).unwrap();
+
+        assert_eq!(log.guesses.len(), 2);
+        assert_eq!(log.guesses[1].answer, "12");
+    }
+
+    #[rstest]
+    #[case("42", Ok(()))]
+    #[case("12", Err("12 was already submitted: wrong"))]
+    #[case("100", Err("100 was already submitted: too high"))]
+    #[case("101", Err("101 is higher than 100, which is too high"))]
+    #[case("9", Err("9 is lower than 10, which is too low"))]
+    #[case("abc", Ok(()))]
+    #[case("", Err("the answer is empty"))]
+    fn test_check(#[case] answer: &str, #[case] expected: Result<(), &str>) {
+        let log = GuessLog {
+            guesses: vec![
+                guess(1, Outcome::TooLow, "10"),
+                guess(1, Outcome::TooHigh, "100"),
+                guess(1, Outcome::Wrong, "12"),
+                // Not checked by the server, so it can be submitted again
+                guess(1, Outcome::Wait(Duration::from_secs(30)), "42"),
+                guess(2, Outcome::Correct, "7"),
+            ],
+        };
+
+        assert_eq!(
+            log.check(2024, 1, 1, answer),
+            expected.map_err(String::from)
+        );
+    }
+
+    #[rstest]
+    fn test_check_solved_part() {
+        let log = GuessLog {
+            guesses: vec![guess(2, Outcome::Correct, "7")],
+        };
+
+        assert_eq!(
+            log.check(2024, 1, 2, "8"),
+            Err("already solved, the answer is 7".to_owned())
+        );
+        assert!(log.check(2023, 1, 2, "8").is_ok());
+    }
+
+    #[rstest]
+    fn test_submit() {
+        let (url, server) = serve_once(200, "<main><p>That's the right answer!</p></main>");
+        let client = Client::new(&url, "abc123".to_owned());
+
+        let g = submit(&client, 2024, 3, 2, "100411201").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(g.outcome, Outcome::Correct);
+        assert_eq!((g.day, g.part, g.answer.as_str()), (3, 2, "100411201"));
+        assert!(request.starts_with("POST /2024/day/3/answer "));
+        assert!(request.contains("Cookie: session=abc123"));
+        assert!(request.ends_with("level=2&answer=100411201"));
+    }
+}
@@ -0,0 +1,61 @@
+//! Helpers shared by the unit tests.
+
+use std::env;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+
+/// Serve a single HTTP request on a local port, answering with `status` and `body`. Returns the
+/// base URL to send the request to, and a handle yielding the request received (headers and
+/// body).
+pub fn serve_once(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        let mut request = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+
+            if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                content_length = len.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+        }
+
+        let mut content = vec![0; content_length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8(content).unwrap());
+
+        write!(
+            stream,
+            "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        request
+    });
+
+    (url, handle)
+}
+
+/// Path of a file named `name` in a temporary directory specific to this test run. The directory
+/// is created if needed.
+pub fn temp_path(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-tests-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir.join(name)
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::iter::zip;
+
+use aoc_common::{columns, Answer, ParseError, PuzzleInput, Solution};
+use itertools::{sorted, Itertools};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse_list_values(input)
+    }
+
+    fn part1((v1, v2): &Self::Input<'_>) -> Answer {
+        get_sum_distances(v1, v2).into()
+    }
+
+    fn part2((v1, v2): &Self::Input<'_>) -> Answer {
+        get_similarity_score(v1, v2).into()
+    }
+}
+
+#[tracing::instrument(skip_all)]
+fn parse_list_values(input: &PuzzleInput) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let [values_a, values_b] = columns(input, "a number")?;
+
+    Ok((values_a, values_b))
+}
+
+#[tracing::instrument(skip_all)]
+fn get_sum_distances(a: &Vec<i32>, b: &Vec<i32>) -> i32 {
+    let a = sorted(a).collect_vec();
+    let b = sorted(b).collect_vec();
+
+    zip(a, b).map(|(v1, v2)| (v1 - v2).abs()).sum()
+}
+
+#[tracing::instrument(skip_all)]
+fn get_similarity_score(a: &[i32], b: &[i32]) -> usize {
+    let mut counts: HashMap<i32, usize> = HashMap::new();
+
+    for v in b {
+        *counts.entry(*v).or_default() += 1;
+    }
+
+    a.iter()
+        .map(|v| (*v as usize) * counts.get(v).unwrap_or(&0))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+        3   4
+        4   3
+        2   5
+        1   3
+        3   9
+        3   3
+        ",
+        )
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day01.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let (v1, v2) = parse_list_values(&test_input).unwrap();
+        let res = get_sum_distances(&v1, &v2);
+
+        assert_eq!(res, 11);
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let (v1, v2) = parse_list_values(&puzzle_input).unwrap();
+        let res = get_sum_distances(&v1, &v2);
+
+        assert_eq!(res, 2192892);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let (v1, v2) = parse_list_values(&test_input).unwrap();
+        let res = get_similarity_score(&v1, &v2);
+
+        assert_eq!(res, 31);
+    }
+
+    #[rstest]
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let (v1, v2) = parse_list_values(&puzzle_input).unwrap();
+        let res = get_similarity_score(&v1, &v2);
+
+        assert_eq!(res, 22962826);
+    }
+
+    #[rstest]
+    fn test_parse_invalid() {
+        let input = parse_test_input("3   4\n4   x\n2");
+
+        let err = parse_list_values(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let err = parse_list_values(&parse_test_input("2")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 2, "a number")
+        );
+    }
+}
@@ -1,46 +1,29 @@
-use std::fmt::Display;
-use std::time::Instant;
-
-use aoc_common::{format_duration, get_input, tracing_init};
+use aoc_common::{lines, Answer, ParseError, PuzzleInput, Solution};
 use itertools::Itertools;
 
-fn main() {
-    tracing_init();
-
-    let input = get_input("day02.txt");
-
-    let start = Instant::now();
+pub struct Day02;
 
-    let (r1, r2) = solve(input.as_slice());
+impl Solution for Day02 {
+    type Input<'a> = Vec<Report>;
 
-    let t = start.elapsed().as_nanos();
-
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse_reports(input)
+    }
 
-#[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let reports = parse_reports(input);
-    let p1 = get_safe_reports(&reports);
-    let p2 = get_safe_reports_with_tolerance(&reports);
+    fn part1(reports: &Self::Input<'_>) -> Answer {
+        get_safe_reports(reports).into()
+    }
 
-    (p1, p2)
+    fn part2(reports: &Self::Input<'_>) -> Answer {
+        get_safe_reports_with_tolerance(reports).into()
+    }
 }
 
 type Report = Vec<i32>;
 
 #[tracing::instrument(skip_all)]
-fn parse_reports(input: &[String]) -> Vec<Report> {
-    input
-        .iter()
-        .map(|i| {
-            i.split(' ')
-                .map(|v| v.parse::<i32>().unwrap())
-                .collect_vec()
-        })
-        .collect_vec()
+fn parse_reports(input: &PuzzleInput) -> Result<Vec<Report>, ParseError> {
+    lines(input).map(|line| line.fields("a number")).collect()
 }
 
 #[tracing::instrument(skip_all)]
@@ -87,13 +70,13 @@ fn is_safe(report: &Vec<i32>) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use aoc_common::parse_test_input;
+    use aoc_common::{get_input, parse_test_input};
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn test_input() -> Vec<String> {
+    fn test_input() -> PuzzleInput {
         parse_test_input(
             "
             7 6 4 2 1
@@ -107,37 +90,37 @@ mod tests {
     }
 
     #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day02.txt")
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day02.txt")
     }
 
     #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let reports = parse_reports(&test_input);
+    fn test_p1(test_input: PuzzleInput) {
+        let reports = parse_reports(&test_input).unwrap();
         let res = get_safe_reports(&reports);
 
         assert_eq!(res, 2);
     }
 
     #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let reports = parse_reports(&puzzle_input);
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let reports = parse_reports(&puzzle_input).unwrap();
         let res = get_safe_reports(&reports);
 
         assert_eq!(res, 332);
     }
 
     #[rstest]
-    fn test_p2(test_input: Vec<String>) {
-        let reports = parse_reports(&test_input);
+    fn test_p2(test_input: PuzzleInput) {
+        let reports = parse_reports(&test_input).unwrap();
         let res = get_safe_reports_with_tolerance(&reports);
 
         assert_eq!(res, 4);
     }
 
     #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
-        let reports = parse_reports(&puzzle_input);
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let reports = parse_reports(&puzzle_input).unwrap();
         let res = get_safe_reports_with_tolerance(&reports);
 
         assert_eq!(res, 398);
@@ -1,37 +1,44 @@
-use std::fmt::Display;
-use std::time::Instant;
-
-use aoc_common::{format_duration, get_input_as_string, tracing_init};
+use aoc_common::{Answer, ParseError, PuzzleInput, Solution};
 use regex::Regex;
 
-fn main() {
-    tracing_init();
-
-    let input = get_input_as_string("day03.txt");
+pub struct Day03;
 
-    let start = Instant::now();
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
 
-    let (r1, r2) = solve(&input);
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.as_str())
+    }
 
-    let t = start.elapsed().as_nanos();
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        add_all_multiplications(input).into()
+    }
 
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        add_enabled_multiplications(input).into()
+    }
 }
 
 #[tracing::instrument(skip_all)]
-fn solve(input: &str) -> (impl Display, impl Display) {
-    add_all_multiplications(input)
+fn add_all_multiplications(input: &str) -> u32 {
+    multiplications(input).iter().map(|(v, _)| v).sum()
 }
 
 #[tracing::instrument(skip_all)]
-fn add_all_multiplications(input: &str) -> (u32, u32) {
+fn add_enabled_multiplications(input: &str) -> u32 {
+    multiplications(input)
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(v, _)| v)
+        .sum()
+}
+
+/// The result of every multiplication, and whether it is enabled by the last `do()` or `don't()`.
+fn multiplications(input: &str) -> Vec<(u32, bool)> {
     let mul_re =
         Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|do\(\)|don't\(\)").expect("Invalid regex");
 
-    let mut sum = 0;
-    let mut partial_sum = 0;
+    let mut res = Vec::new();
 
     let mut enabled = true;
 
@@ -43,41 +50,37 @@ fn add_all_multiplications(input: &str) -> (u32, u32) {
                 let a = m.get(1).unwrap().as_str().parse::<u32>().unwrap();
                 let b = m.get(2).unwrap().as_str().parse::<u32>().unwrap();
 
-                let v = a * b;
-
-                sum += v;
-                if enabled {
-                    partial_sum += v
-                }
+                res.push((a * b, enabled));
             }
         }
     }
 
-    (sum, partial_sum)
+    res
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::get_input_as_string;
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
     fn puzzle_input() -> String {
-        get_input_as_string("day03.txt")
+        get_input_as_string("2024/day03.txt")
     }
 
     #[rstest]
     fn test_p1() {
         let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        let (res, _) = add_all_multiplications(input);
+        let res = add_all_multiplications(input);
 
         assert_eq!(res, 161);
     }
 
     #[rstest]
     fn test_p1_full_input(puzzle_input: String) {
-        let (res, _) = add_all_multiplications(&puzzle_input);
+        let res = add_all_multiplications(&puzzle_input);
 
         assert_eq!(res, 174103751);
     }
@@ -85,14 +88,14 @@ mod tests {
     #[rstest]
     fn test_p2() {
         let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        let (_, res) = add_all_multiplications(input);
+        let res = add_enabled_multiplications(input);
 
         assert_eq!(res, 48);
     }
 
     #[rstest]
     fn test_p2_full_input(puzzle_input: String) {
-        let (_, res) = add_all_multiplications(&puzzle_input);
+        let res = add_enabled_multiplications(&puzzle_input);
 
         assert_eq!(res, 100411201);
     }
@@ -0,0 +1,128 @@
+use aoc_common::{Answer, Direction8, GridView, ParseError, Point, PuzzleInput, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = GridView<'a>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        input.grid()
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        count_xmases(grid).into()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        count_x_mases(grid).into()
+    }
+}
+
+#[tracing::instrument(skip_all)]
+fn count_xmases(grid: &GridView) -> usize {
+    grid.positions_of(b'X')
+        .map(|x| {
+            Direction8::ALL
+                .iter()
+                .filter(|d| spells(grid, x, d.delta(), b"XMAS"))
+                .count()
+        })
+        .sum()
+}
+
+#[tracing::instrument(skip_all)]
+fn count_x_mases(grid: &GridView) -> usize {
+    grid.positions_of(b'A')
+        .filter(|&a| {
+            let diagonal = |d: Direction8| {
+                let start = a.checked_offset(d.delta())?;
+
+                Some(spells(grid, start, d.reverse().delta(), b"MAS"))
+            };
+
+            let down = diagonal(Direction8::UpLeft).unwrap_or(false)
+                || diagonal(Direction8::DownRight).unwrap_or(false);
+            let up = diagonal(Direction8::DownLeft).unwrap_or(false)
+                || diagonal(Direction8::UpRight).unwrap_or(false);
+
+            down && up
+        })
+        .count()
+}
+
+/// Whether `word` can be read in the grid from `start`, moving by `step` for each letter.
+fn spells(grid: &GridView, start: Point<usize>, step: Point<i64>, word: &[u8]) -> bool {
+    let mut p = Some(start);
+
+    for &c in word {
+        match p.and_then(|p| grid.get(p)) {
+            Some(g) if g == c => p = p.and_then(|p| p.checked_offset(step)),
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+            MMMSXXMASM
+            MSAMXMSMSA
+            AMXSXMAAMM
+            MSAMASMSMX
+            XMASAMXAMM
+            XXAMMXXAMA
+            SMSMSASXSS
+            SAXAMASAAA
+            MAMMMXMMMM
+            MXMXAXMASX
+        ",
+        )
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day04.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let grid = test_input.grid().unwrap();
+        let res = count_xmases(&grid);
+
+        assert_eq!(res, 18);
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let grid = puzzle_input.grid().unwrap();
+        let res = count_xmases(&grid);
+
+        assert_eq!(res, 2500);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let grid = test_input.grid().unwrap();
+        let res = count_x_mases(&grid);
+
+        assert_eq!(res, 9);
+    }
+
+    #[rstest]
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let grid = puzzle_input.grid().unwrap();
+        let res = count_x_mases(&grid);
+
+        assert_eq!(res, 1933);
+    }
+}
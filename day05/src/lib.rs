@@ -1,33 +1,22 @@
-use std::fmt::Display;
-use std::time::Instant;
-
-use aoc_common::{format_duration, get_input, tracing_init};
+use aoc_common::{blocks, Answer, ParseError, PuzzleInput, Solution};
 use itertools::Itertools;
 
-fn main() {
-    tracing_init();
-
-    let input = get_input("day05.txt");
-
-    let start = Instant::now();
+pub struct Day05;
 
-    let (r1, r2) = solve(input.as_slice());
-
-    let t = start.elapsed().as_nanos();
-
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
+impl Solution for Day05 {
+    type Input<'a> = SafetyManual;
 
-#[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let manual = parse_manual(input);
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse_manual(input)
+    }
 
-    let p1 = manual.get_hash_of_ordered_updates();
-    let p2 = manual.get_hash_of_fixed_updates();
+    fn part1(manual: &Self::Input<'_>) -> Answer {
+        manual.get_hash_of_ordered_updates().into()
+    }
 
-    (p1, p2)
+    fn part2(manual: &Self::Input<'_>) -> Answer {
+        manual.get_hash_of_fixed_updates().into()
+    }
 }
 
 struct PageOrdering {
@@ -97,7 +86,7 @@ impl PageList {
     }
 }
 
-struct SafetyManual {
+pub struct SafetyManual {
     ordering_rules: Vec<PageOrdering>,
     pages_to_produce: Vec<PageList>,
 }
@@ -135,41 +124,49 @@ impl SafetyManual {
 }
 
 #[tracing::instrument(skip_all)]
-fn parse_manual(input: &[String]) -> SafetyManual {
-    let sep = input.iter().position(|s| s.is_empty()).unwrap();
-
-    let mut ordering_rules = Vec::with_capacity(sep);
-    let mut pages_to_produce = Vec::with_capacity(input.len() - sep - 1);
-
-    for e in input.iter().take(sep) {
-        let (p1, p2) = e
-            .splitn(2, '|')
-            .map(|p| p.parse::<u8>().unwrap())
-            .collect_tuple()
-            .unwrap();
-        ordering_rules.push(PageOrdering { p1, p2 })
-    }
-
-    for e in input.iter().skip(sep + 1) {
-        let pages = e.split(',').map(|p| p.parse::<u8>().unwrap()).collect_vec();
-        pages_to_produce.push(PageList { pages })
-    }
-
-    SafetyManual {
+fn parse_manual(input: &PuzzleInput) -> Result<SafetyManual, ParseError> {
+    let mut blocks = blocks(input);
+    let (Some(rules), Some(updates)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::input(
+            "a blank line between the rules and the updates",
+        ));
+    };
+
+    let ordering_rules = rules
+        .iter()
+        .map(|line| {
+            let (p1, p2) = line.split_pair("|")?;
+
+            Ok(PageOrdering {
+                p1: p1.value("a page number")?,
+                p2: p2.value("a page number")?,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+    let pages_to_produce = updates
+        .iter()
+        .map(|line| {
+            Ok(PageList {
+                pages: line.list(",", "a page number")?,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(SafetyManual {
         ordering_rules,
         pages_to_produce,
-    }
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use aoc_common::parse_test_input;
+    use aoc_common::{get_input, parse_test_input};
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn test_input() -> Vec<String> {
+    fn test_input() -> PuzzleInput {
         parse_test_input(
             "
             47|53
@@ -205,45 +202,55 @@ mod tests {
     }
 
     #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day05.txt")
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day05.txt")
     }
 
     #[rstest]
-    fn test_parse_manual(test_input: Vec<String>) {
-        let manual = parse_manual(&test_input);
+    fn test_parse_manual(test_input: PuzzleInput) {
+        let manual = parse_manual(&test_input).unwrap();
 
         assert_eq!(manual.ordering_rules.len(), 21);
         assert_eq!(manual.pages_to_produce.len(), 6);
     }
 
     #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let manual = parse_manual(&test_input);
+    #[case::no_separator("47|53\n75,47", 0, 0)]
+    #[case::bad_rule("47|53\n9753\n\n75,47", 2, 5)]
+    #[case::bad_page("47|53\n\n75,x7", 3, 4)]
+    fn test_parse_manual_invalid(#[case] input: &str, #[case] line: usize, #[case] column: usize) {
+        let err = parse_manual(&PuzzleInput::from(input)).err().unwrap();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let manual = parse_manual(&test_input).unwrap();
         let res = manual.get_hash_of_ordered_updates();
 
         assert_eq!(res, 143);
     }
 
     #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let manual = parse_manual(&puzzle_input);
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let manual = parse_manual(&puzzle_input).unwrap();
         let res = manual.get_hash_of_ordered_updates();
 
         assert_eq!(res, 6034);
     }
 
     #[rstest]
-    fn test_p2(test_input: Vec<String>) {
-        let manual = parse_manual(&test_input);
+    fn test_p2(test_input: PuzzleInput) {
+        let manual = parse_manual(&test_input).unwrap();
         let res = manual.get_hash_of_fixed_updates();
 
         assert_eq!(res, 123);
     }
 
     #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
-        let manual = parse_manual(&puzzle_input);
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let manual = parse_manual(&puzzle_input).unwrap();
         let res = manual.get_hash_of_fixed_updates();
 
         assert_eq!(res, 6305);
@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{lines, Answer, Direction, Grid, ParseError, Point, PuzzleInput, Solution};
+use rayon::prelude::*;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Map;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Answer {
+        map.clone().get_path().len().into()
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Answer {
+        let path = map.clone().get_path();
+
+        map.find_loops(&path).into()
+    }
+}
+
+type Position = Point<usize>;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Tile {
+    Open,
+    Obstacle,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Open => write!(f, "."),
+            Tile::Obstacle => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    guard_pos: Position,
+    guard_dir: Direction,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut tiles = self.tiles.map(|t| t.to_string());
+        tiles[self.guard_pos] = "^".to_string();
+
+        write!(f, "{}", tiles)
+    }
+}
+
+impl TryFrom<&PuzzleInput> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &PuzzleInput) -> Result<Self, Self::Error> {
+        let chars = Grid::try_parse(
+            lines(value),
+            |c| matches!(c, '#' | '.' | '^').then_some(c),
+            "\"#\", \".\" or \"^\"",
+        )?;
+        let guard_pos = chars
+            .find(|&c| c == '^')
+            .ok_or_else(|| ParseError::input("a guard (\"^\")"))?;
+
+        Ok(Self {
+            tiles: chars.map(|&c| match c {
+                '#' => Tile::Obstacle,
+                _ => Tile::Open,
+            }),
+            guard_pos,
+            guard_dir: Direction::Up,
+        })
+    }
+}
+
+impl Map {
+    fn get_path(&mut self) -> Vec<Position> {
+        let mut visited = HashSet::new();
+
+        visited.insert(self.guard_pos);
+
+        loop {
+            if let Err(()) = self.move_guard() {
+                break;
+            }
+
+            visited.insert(self.guard_pos);
+        }
+
+        visited.iter().copied().collect()
+    }
+
+    fn find_loops(&self, path: &[Position]) -> usize {
+        path.into_par_iter()
+            // Skip the starting position
+            .filter(|&&p| p != self.guard_pos)
+            .filter(|&p| {
+                tracing::trace!(x = p.x, y = p.y, "testing an obstacle");
+
+                let mut map = self.clone();
+                map.tiles[*p] = Tile::Obstacle;
+
+                let mut visited = Vec::new();
+                visited.push((map.guard_pos, map.guard_dir));
+
+                loop {
+                    if let Err(()) = map.move_guard() {
+                        return false;
+                    }
+
+                    if visited.contains(&(map.guard_pos, map.guard_dir)) {
+                        return true;
+                    }
+
+                    visited.push((map.guard_pos, map.guard_dir));
+                }
+            })
+            .count()
+    }
+
+    fn move_guard(&mut self) -> Result<(), ()> {
+        let next = self
+            .guard_pos
+            .checked_offset(self.guard_dir.delta())
+            .filter(|&p| self.tiles.contains(p))
+            .ok_or(())?;
+
+        if self.tiles[next] == Tile::Obstacle {
+            self.guard_dir = self.guard_dir.turn_right();
+        } else {
+            self.guard_pos = next;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+            ....#.....
+            .........#
+            ..........
+            ..#.......
+            .......#..
+            ..........
+            .#..^.....
+            ........#.
+            #.........
+            ......#...
+        ",
+        )
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day06.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let mut map = Map::try_from(&test_input).unwrap();
+        let res = map.get_path().len();
+
+        assert_eq!(res, 41);
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let mut map = Map::try_from(&puzzle_input).unwrap();
+        let res = map.get_path().len();
+
+        assert_eq!(res, 4826);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let mut map = Map::try_from(&test_input).unwrap();
+        let m2 = map.clone();
+
+        let path = map.get_path();
+        let res = m2.find_loops(&path);
+
+        assert_eq!(res, 6);
+    }
+
+    #[rstest]
+    #[ignore] // Test is very slow
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let mut map = Map::try_from(&puzzle_input).unwrap();
+        let m2 = map.clone();
+
+        let path = map.get_path();
+        let res = m2.find_loops(&path);
+
+        assert_eq!(res, 1721);
+    }
+}
@@ -1,36 +1,25 @@
-use std::fmt::Display;
-use std::time::Instant;
-
-use aoc_common::{format_duration, get_input, tracing_init};
-use itertools::Itertools;
+use aoc_common::{lines, Answer, Line, ParseError, PuzzleInput, Solution};
 use rayon::prelude::*;
 
-fn main() {
-    tracing_init();
-
-    let input = get_input("day07.txt");
-
-    let start = Instant::now();
+pub struct Day07;
 
-    let (r1, r2) = solve(input.as_slice());
+impl Solution for Day07 {
+    type Input<'a> = Vec<Equation>;
 
-    let t = start.elapsed().as_nanos();
-
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse_equations(input)
+    }
 
-#[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let eqs = parse_equations(input);
-    let p1 = get_total_calibration_result(&eqs);
-    let p2 = get_total_calibration_result_with_concat(&eqs);
+    fn part1(eqs: &Self::Input<'_>) -> Answer {
+        get_total_calibration_result(eqs).into()
+    }
 
-    (p1, p2)
+    fn part2(eqs: &Self::Input<'_>) -> Answer {
+        get_total_calibration_result_with_concat(eqs).into()
+    }
 }
 
-struct Equation {
+pub struct Equation {
     target: u64,
     values: Vec<u64>,
 }
@@ -108,42 +97,27 @@ impl Equation {
     }
 }
 
-impl TryFrom<&String> for Equation {
-    type Error = String;
+impl TryFrom<Line<'_>> for Equation {
+    type Error = ParseError;
 
-    fn try_from(value: &String) -> Result<Self, Self::Error> {
-        let (target, values) = value.split(':').collect_tuple().unwrap();
-
-        let target = match target.parse::<u64>() {
-            Ok(t) => t,
-            Err(e) => return Err(format!("unable to parse target: {}: {:?}", target, e)),
-        };
-
-        let values = match values
-            .split(' ')
-            .filter_map(|v| {
-                let v = v.trim();
-                if v.is_empty() {
-                    return None;
-                }
-                Some(v.parse::<u64>())
-            })
-            .collect()
-        {
-            Ok(v) => v,
-            Err(e) => return Err(format!("unable to parse values: {}: {:?}", values, e)),
-        };
+    fn try_from(line: Line<'_>) -> Result<Self, Self::Error> {
+        let (target, values) = line.split_pair(":")?;
+        let target = target.value("a target value")?;
+        let numbers = values.fields("a number")?;
+        if numbers.is_empty() {
+            return Err(values.error_at_end("a number"));
+        }
 
-        Ok(Equation { target, values })
+        Ok(Equation {
+            target,
+            values: numbers,
+        })
     }
 }
 
 #[tracing::instrument(skip_all)]
-fn parse_equations(input: &[String]) -> Vec<Equation> {
-    input
-        .iter()
-        .map(|i| Equation::try_from(i).unwrap())
-        .collect()
+fn parse_equations(input: &PuzzleInput) -> Result<Vec<Equation>, ParseError> {
+    lines(input).map(Equation::try_from).collect()
 }
 
 #[tracing::instrument(skip_all)]
@@ -163,13 +137,13 @@ fn get_total_calibration_result_with_concat(eqs: &[Equation]) -> u64 {
 }
 #[cfg(test)]
 mod tests {
-    use aoc_common::parse_test_input;
+    use aoc_common::{get_input, parse_test_input};
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn test_input() -> Vec<String> {
+    fn test_input() -> PuzzleInput {
         parse_test_input(
             "
             190: 10 19
@@ -186,37 +160,53 @@ mod tests {
     }
 
     #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day07.txt")
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day07.txt")
     }
 
     #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let eqs = parse_equations(&test_input);
+    fn test_p1(test_input: PuzzleInput) {
+        let eqs = parse_equations(&test_input).unwrap();
         let res = get_total_calibration_result(&eqs);
 
         assert_eq!(res, 3749);
     }
 
     #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let eqs = parse_equations(&puzzle_input);
+    #[case::no_separator("190 10 19", 1, 10)]
+    #[case::bad_target("19x: 10 19", 1, 1)]
+    #[case::bad_value("190: 10 y9", 1, 9)]
+    #[case::no_values("190:", 1, 5)]
+    #[case::blank_values("190:   ", 1, 8)]
+    fn test_parse_equations_invalid(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+    ) {
+        let err = parse_equations(&PuzzleInput::from(input)).err().unwrap();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let eqs = parse_equations(&puzzle_input).unwrap();
         let res = get_total_calibration_result(&eqs);
 
         assert_eq!(res, 12940396350192);
     }
 
     #[rstest]
-    fn test_p2(test_input: Vec<String>) {
-        let eqs = parse_equations(&test_input);
+    fn test_p2(test_input: PuzzleInput) {
+        let eqs = parse_equations(&test_input).unwrap();
         let res = get_total_calibration_result_with_concat(&eqs);
 
         assert_eq!(res, 11387);
     }
 
     #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
-        let eqs = parse_equations(&puzzle_input);
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let eqs = parse_equations(&puzzle_input).unwrap();
         let res = get_total_calibration_result_with_concat(&eqs);
 
         assert_eq!(res, 106016735664498);
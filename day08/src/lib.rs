@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use aoc_common::{lines, Answer, Grid, ParseError, Point, PuzzleInput, Solution};
+use itertools::Itertools;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Map;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Answer {
+        map.count_unique_antinode_pos().into()
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Answer {
+        map.count_unique_real_antinode_pos().into()
+    }
+}
+
+type Position = Point<usize>;
+
+struct Antenna {
+    freq: char,
+    pos: Position,
+}
+
+pub struct Map {
+    grid: Grid<char>,
+    antinodes: Vec<Position>,
+    real_antinodes: Vec<Position>,
+}
+
+impl TryFrom<&PuzzleInput> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &PuzzleInput) -> Result<Self, Self::Error> {
+        let grid = Grid::try_parse(
+            lines(value),
+            |c| (c == '.' || c.is_ascii_alphanumeric()).then_some(c),
+            "\".\" or an antenna",
+        )?;
+
+        let antennas = grid
+            .iter()
+            .filter(|(_, &c)| c != '.')
+            .map(|(pos, &freq)| Antenna { pos, freq })
+            .collect_vec();
+
+        let antinodes = Self::get_antinodes(&antennas, grid.width(), grid.height());
+        let real_antinodes = Self::get_real_antinodes(&antennas, grid.width(), grid.height());
+
+        Ok(Self {
+            grid,
+            antinodes,
+            real_antinodes,
+        })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = self.grid.clone();
+
+        for &p in &self.real_antinodes {
+            if grid[p] == '.' {
+                grid[p] = '#';
+            }
+        }
+
+        write!(f, "{}", grid)
+    }
+}
+
+impl Map {
+    fn get_antinodes(antennas: &[Antenna], width: usize, height: usize) -> Vec<Position> {
+        let mut antinodes = Vec::new();
+
+        let antenna_by_freq = {
+            let mut map: HashMap<char, Vec<Position>> = HashMap::new();
+
+            for a in antennas {
+                map.entry(a.freq)
+                    .and_modify(|v| v.push(a.pos))
+                    .or_insert(vec![a.pos]);
+            }
+
+            map
+        };
+
+        for (_, positions) in antenna_by_freq {
+            for pp in positions.iter().combinations(2) {
+                let a = Point::<i64>::from(*pp[0]);
+                let b = Point::<i64>::from(*pp[1]);
+                let delta = b - a;
+
+                for p in [a - delta, b + delta] {
+                    if let Some(p) = Self::within(p, width, height) {
+                        antinodes.push(p);
+                    }
+                }
+            }
+        }
+
+        antinodes
+    }
+
+    fn get_real_antinodes(antennas: &[Antenna], width: usize, height: usize) -> Vec<Position> {
+        let mut antinodes = Vec::new();
+
+        let antenna_by_freq = {
+            let mut map: HashMap<char, Vec<Position>> = HashMap::new();
+
+            for a in antennas {
+                map.entry(a.freq)
+                    .and_modify(|v| v.push(a.pos))
+                    .or_insert(vec![a.pos]);
+            }
+
+            map
+        };
+
+        for (_, positions) in antenna_by_freq {
+            for pp in positions.iter().combinations(2) {
+                let a = Point::<i64>::from(*pp[0]);
+                let b = Point::<i64>::from(*pp[1]);
+                let delta = b - a;
+
+                for (start, step) in [(a, -delta), (b, delta)] {
+                    let mut p = start;
+
+                    while let Some(pos) = Self::within(p, width, height) {
+                        antinodes.push(pos);
+                        p = p + step;
+                    }
+                }
+            }
+        }
+
+        antinodes
+    }
+
+    /// Convert `p` to a position on the map, if it's within its bounds.
+    fn within(p: Point<i64>, width: usize, height: usize) -> Option<Position> {
+        Position::try_from(p)
+            .ok()
+            .filter(|p| p.x < width && p.y < height)
+    }
+
+    fn count_unique_antinode_pos(&self) -> usize {
+        self.antinodes.iter().collect::<HashSet<&Position>>().len()
+    }
+
+    fn count_unique_real_antinode_pos(&self) -> usize {
+        self.real_antinodes
+            .iter()
+            .collect::<HashSet<&Position>>()
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+            ............
+            ........0...
+            .....0......
+            .......0....
+            ....0.......
+            ......A.....
+            ............
+            ............
+            ........A...
+            .........A..
+            ............
+            ............
+        ",
+        )
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day08.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let map = Map::try_from(&test_input).unwrap();
+        let res = map.count_unique_antinode_pos();
+
+        assert_eq!(res, 14);
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let map = Map::try_from(&puzzle_input).unwrap();
+        let res = map.count_unique_antinode_pos();
+
+        assert_eq!(res, 247);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let map = Map::try_from(&test_input).unwrap();
+        println!("{}", map);
+        let res = map.count_unique_real_antinode_pos();
+
+        assert_eq!(res, 34);
+    }
+
+    #[rstest]
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let map = Map::try_from(&puzzle_input).unwrap();
+        println!("{}", map);
+        let res = map.count_unique_real_antinode_pos();
+
+        assert_eq!(res, 861);
+    }
+}
@@ -1,37 +1,35 @@
 use std::collections::VecDeque;
-use std::fmt::Display;
-use std::time::Instant;
 
-use aoc_common::{format_duration, get_input_as_string, tracing_init};
+use aoc_common::{lines, Answer, ParseError, PuzzleInput, Solution};
 
-fn main() {
-    tracing_init();
+pub struct Day09;
 
-    let input = get_input_as_string("day09.txt");
+impl Solution for Day09 {
+    type Input<'a> = (Disk, UnfragmentedDisk);
 
-    let start = Instant::now();
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        let line = lines(input)
+            .next()
+            .ok_or_else(|| ParseError::input("a disk map"))?;
+        let input = line.text();
 
-    let (r1, r2) = solve(input.as_str());
-
-    let t = start.elapsed().as_nanos();
-
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
+        if let Some(idx) = input.find(|c: char| !c.is_ascii_digit()) {
+            return Err(line.error(&input[idx..], "a digit"));
+        }
 
-#[tracing::instrument(skip_all)]
-fn solve(input: &str) -> (impl Display, impl Display) {
-    let mut disk = Disk::from(input);
-    disk.optimize();
+        Ok((Disk::from(input), UnfragmentedDisk::from(input)))
+    }
 
-    let p1 = disk.checksum();
+    fn part1((disk, _): &Self::Input<'_>) -> Answer {
+        let mut disk = disk.clone();
+        disk.optimize();
 
-    let disk = UnfragmentedDisk::from(input);
-    let disk = disk.optimized();
-    let p2 = disk.checksum();
+        disk.checksum().into()
+    }
 
-    (p1, p2)
+    fn part2((_, unfragmented): &Self::Input<'_>) -> Answer {
+        unfragmented.optimized().checksum().into()
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -40,7 +38,8 @@ enum BlockType {
     File(u32),
 }
 
-struct Disk {
+#[derive(Clone)]
+pub struct Disk {
     blocks: Vec<BlockType>,
 }
 
@@ -111,7 +110,7 @@ struct Block {
     pos: usize,
 }
 
-struct UnfragmentedDisk {
+pub struct UnfragmentedDisk {
     blocks: Vec<Block>,
 }
 
@@ -213,6 +212,7 @@ impl UnfragmentedDisk {
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::get_input_as_string;
     use rstest::{fixture, rstest};
 
     use super::*;
@@ -224,7 +224,7 @@ mod tests {
 
     #[fixture]
     fn puzzle_input() -> String {
-        get_input_as_string("day09.txt")
+        get_input_as_string("2024/day09.txt")
     }
 
     #[rstest]
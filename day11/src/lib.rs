@@ -0,0 +1,126 @@
+use aoc_common::{lines, Answer, Line, Param, Params, ParseError, PuzzleInput, Solution};
+use cached::proc_macro::cached;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = (Vec<u64>, [i32; 2]);
+
+    // The number of stones overflows beyond 100 blinks.
+    const PARAMS: &'static [Param] = &[
+        Param::new("blinks1", 25, 0..=100),
+        Param::new("blinks2", 75, 0..=100),
+    ];
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with_params(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with_params<'a>(
+        input: &'a PuzzleInput,
+        params: &Params,
+    ) -> Result<Self::Input<'a>, ParseError> {
+        let line = lines(input)
+            .next()
+            .ok_or_else(|| ParseError::input("a line of stones"))?;
+
+        let blinks = [params.try_get("blinks1")?, params.try_get("blinks2")?];
+
+        Ok((parse_stones(line)?, blinks))
+    }
+
+    fn part1((stones, [blinks, _]): &Self::Input<'_>) -> Answer {
+        get_number_of_stones(stones.clone(), *blinks).into()
+    }
+
+    fn part2((stones, [_, blinks]): &Self::Input<'_>) -> Answer {
+        get_number_of_stones(stones.clone(), *blinks).into()
+    }
+}
+
+#[tracing::instrument(skip_all)]
+fn parse_stones(line: Line<'_>) -> Result<Vec<u64>, ParseError> {
+    line.list(" ", "a number")
+}
+
+#[tracing::instrument(skip_all)]
+fn get_number_of_stones(stones: Vec<u64>, n: i32) -> usize {
+    let mut count = 0;
+
+    for s in stones {
+        count += blink(s, n);
+    }
+
+    count
+}
+
+#[cached]
+fn blink(s: u64, n: i32) -> usize {
+    if n == 0 {
+        return 1;
+    }
+
+    if s == 0 {
+        return blink(1, n - 1);
+    }
+
+    let nb = (s as f64).log10().floor() as u32 + 1;
+    if nb.is_multiple_of(2) {
+        let e = 10u64.pow(nb / 2);
+        let a = s / e;
+        let b = s % e;
+        return blink(a, n - 1) + blink(b, n - 1);
+    }
+
+    return blink(s * 2024, n - 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::get_input_as_string;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn test_input() -> String {
+        String::from("125 17")
+    }
+
+    #[fixture]
+    fn puzzle_input() -> String {
+        get_input_as_string("2024/day11.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: String) {
+        let stones = parse_stones(Line::new(0, &test_input)).unwrap();
+        let res = get_number_of_stones(stones, 25);
+
+        assert_eq!(res, 55312);
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: String) {
+        let stones = parse_stones(Line::new(0, &puzzle_input)).unwrap();
+        let res = get_number_of_stones(stones, 25);
+
+        assert_eq!(res, 213625);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: String) {
+        let stones = parse_stones(Line::new(0, &test_input)).unwrap();
+        let res = get_number_of_stones(stones, 75);
+
+        assert_eq!(res, 65601038650482);
+    }
+
+    #[rstest]
+    fn test_p2_full_input(puzzle_input: String) {
+        let stones = parse_stones(Line::new(0, &puzzle_input)).unwrap();
+        let res = get_number_of_stones(stones, 75);
+
+        assert_eq!(res, 252442982856820);
+    }
+}
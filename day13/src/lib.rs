@@ -1,68 +1,56 @@
-use std::fmt::Display;
-use std::time::Instant;
-
-use aoc_common::{format_duration, get_input, tracing_init, Point};
+use aoc_common::{blocks, Answer, Param, Params, ParseError, Point, PuzzleInput, Solution};
 use mathru::algebra::linear::matrix::{General, Solve};
 use mathru::algebra::linear::vector::Vector;
 use mathru::vector;
-use regex::Regex;
-
-fn main() {
-    tracing_init();
 
-    let input = get_input("day13.txt");
+pub struct Day13;
 
-    let start = Instant::now();
+impl Solution for Day13 {
+    type Input<'a> = (Vec<Machine>, u64);
 
-    let (r1, r2) = solve(input.as_slice());
+    const PARAMS: &'static [Param] = &[Param::new("offset", 10000000000000, 0..=1000000000000000)];
 
-    let t = start.elapsed().as_nanos();
-
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with_params(input, &Params::new(Self::PARAMS))
+    }
 
-#[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let machines = parse_machines(input);
+    fn parse_with_params<'a>(
+        input: &'a PuzzleInput,
+        params: &Params,
+    ) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse_machines(input)?, params.try_get("offset")?))
+    }
 
-    let p1 = get_fewest_tokens(&machines, 0);
-    let p2 = get_fewest_tokens(&machines, 10000000000000);
+    fn part1((machines, _): &Self::Input<'_>) -> Answer {
+        get_fewest_tokens(machines, 0).into()
+    }
 
-    (p1, p2)
+    fn part2((machines, offset): &Self::Input<'_>) -> Answer {
+        get_fewest_tokens(machines, *offset).into()
+    }
 }
 
 #[tracing::instrument(skip_all)]
-fn parse_machines(input: &[String]) -> Vec<Machine> {
-    let mut machines = Vec::new();
-
-    let regex = Regex::new(r"[A-Za-z ]+: X[=\+](\d+), Y[=\+](\d+)").expect("Invalid regex");
-
-    for blocks in input.split(|s| s.is_empty()) {
-        let btn_a_m = regex.captures(&blocks[0]).expect("invalid btn_a");
-        let btn_b_m = regex.captures(&blocks[1]).expect("invalid btn_b");
-        let prize_m = regex.captures(&blocks[2]).expect("invalid prize");
-
-        let m = Machine {
-            btn_a: Button::new(
-                btn_a_m.get(1).unwrap().as_str().parse::<u64>().unwrap(),
-                btn_a_m.get(2).unwrap().as_str().parse::<u64>().unwrap(),
-            ),
-            btn_b: Button::new(
-                btn_b_m.get(1).unwrap().as_str().parse::<u64>().unwrap(),
-                btn_b_m.get(2).unwrap().as_str().parse::<u64>().unwrap(),
-            ),
-            prize: Prize::new(
-                prize_m.get(1).unwrap().as_str().parse::<u64>().unwrap(),
-                prize_m.get(2).unwrap().as_str().parse::<u64>().unwrap(),
-            ),
-        };
-
-        machines.push(m);
-    }
-
-    machines
+fn parse_machines(input: &PuzzleInput) -> Result<Vec<Machine>, ParseError> {
+    blocks(input)
+        .map(|block| {
+            let point = |idx: usize, label: &str| {
+                let line = block.get(idx).ok_or_else(|| {
+                    block[block.len() - 1]
+                        .error_at_end(format!("a {:?} line after this one", label))
+                })?;
+                let [x, y] = line.strip_prefix(&format!("{}: ", label))?.ints_n()?;
+
+                Ok::<_, ParseError>(Point::new(x, y))
+            };
+
+            Ok(Machine {
+                btn_a: point(0, "Button A")?,
+                btn_b: point(1, "Button B")?,
+                prize: point(2, "Prize")?,
+            })
+        })
+        .collect()
 }
 
 #[tracing::instrument(skip(machines))]
@@ -89,7 +77,7 @@ impl Presses {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Machine {
+pub struct Machine {
     btn_a: Button,
     btn_b: Button,
     prize: Prize,
@@ -131,13 +119,13 @@ impl Machine {
 
 #[cfg(test)]
 mod tests {
-    use aoc_common::parse_test_input;
+    use aoc_common::{get_input, parse_test_input};
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn test_input() -> Vec<String> {
+    fn test_input() -> PuzzleInput {
         parse_test_input(
             "
             Button A: X+94, Y+34
@@ -160,37 +148,37 @@ mod tests {
     }
 
     #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day13.txt")
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day13.txt")
     }
 
     #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let machines = parse_machines(&test_input);
+    fn test_p1(test_input: PuzzleInput) {
+        let machines = parse_machines(&test_input).unwrap();
         let res = get_fewest_tokens(&machines, 0);
 
         assert_eq!(res, 480);
     }
 
     #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let machines = parse_machines(&puzzle_input);
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let machines = parse_machines(&puzzle_input).unwrap();
         let res = get_fewest_tokens(&machines, 0);
 
         assert_eq!(res, 38839);
     }
 
     #[rstest]
-    fn test_p2(test_input: Vec<String>) {
-        let machines = parse_machines(&test_input);
+    fn test_p2(test_input: PuzzleInput) {
+        let machines = parse_machines(&test_input).unwrap();
         let res = get_fewest_tokens(&machines, 10000000000000);
 
         assert_eq!(res, 875318608908);
     }
 
     #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
-        let machines = parse_machines(&puzzle_input);
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let machines = parse_machines(&puzzle_input).unwrap();
         let res = get_fewest_tokens(&machines, 10000000000000);
 
         assert_eq!(res, 75200131617108);
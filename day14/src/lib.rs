@@ -1,35 +1,41 @@
 use core::f32;
 use std::cmp::Ordering;
 use std::fmt::Display;
-use std::time::Instant;
 
-use aoc_common::{format_duration, get_input, tracing_init, Point};
-use regex::Regex;
+use aoc_common::{lines, Answer, Grid, Param, Params, ParseError, Point, PuzzleInput, Solution};
 
-fn main() {
-    tracing_init();
+pub struct Day14;
 
-    let input = get_input("day14.txt");
+impl Solution for Day14 {
+    type Input<'a> = (Map, i32);
 
-    let start = Instant::now();
+    // With velocities below 1000, the positions after any number of ticks fit in an i32.
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 101, 1..=10000),
+        Param::new("height", 103, 1..=10000),
+        Param::new("ticks", 100, 0..=1000000),
+    ];
 
-    let (r1, r2) = solve(input.as_slice());
-
-    let t = start.elapsed().as_nanos();
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with_params(input, &Params::new(Self::PARAMS))
+    }
 
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
+    fn parse_with_params<'a>(
+        input: &'a PuzzleInput,
+        params: &Params,
+    ) -> Result<Self::Input<'a>, ParseError> {
+        let map = parse_map(input, params.try_get("width")?, params.try_get("height")?)?;
 
-#[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let mut map = parse_map(input, 101, 103);
+        Ok((map, params.try_get("ticks")?))
+    }
 
-    let p1 = get_factor_after_ticks(map.clone(), 100);
-    let p2 = get_tick_least_deviation(&mut map);
+    fn part1((map, ticks): &Self::Input<'_>) -> Answer {
+        get_factor_after_ticks(map.clone(), *ticks).into()
+    }
 
-    (p1, p2)
+    fn part2((map, _): &Self::Input<'_>) -> Answer {
+        get_tick_least_deviation(&mut map.clone()).into()
+    }
 }
 
 #[tracing::instrument(skip_all)]
@@ -94,7 +100,7 @@ type Position = Point<i32>;
 type Velocity = Point<i32>;
 
 #[derive(Clone)]
-struct Map {
+pub struct Map {
     width: i32,
     height: i32,
 
@@ -103,9 +109,10 @@ struct Map {
 
 impl Map {
     fn tick(&mut self, n: i32) {
+        let size = Point::new(self.width, self.height);
+
         for r in self.robots.iter_mut() {
-            r.position.x = (r.position.x + r.velocity.x * n).rem_euclid(self.width);
-            r.position.y = (r.position.y + r.velocity.y * n).rem_euclid(self.height);
+            r.position = r.position.wrapping_offset(r.velocity * n, size);
         }
     }
 
@@ -139,19 +146,13 @@ const NUM_CHARS: &[char] = &[
 
 impl Display for Map {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let c = self
-                    .robots
-                    .iter()
-                    .filter(|r| r.position.x == x && r.position.y == y)
-                    .count();
-                write!(f, "{}", NUM_CHARS[c])?;
-            }
-            writeln!(f)?;
+        let mut counts = Grid::filled(self.width as usize, self.height as usize, 0);
+
+        for r in &self.robots {
+            counts[Point::new(r.position.x as usize, r.position.y as usize)] += 1;
         }
 
-        Ok(())
+        write!(f, "{}", counts.map(|&c: &usize| NUM_CHARS[c]))
     }
 }
 
@@ -168,43 +169,34 @@ impl Robot {
 }
 
 #[tracing::instrument(skip_all)]
-fn parse_map(input: &[String], width: i32, height: i32) -> Map {
-    let re = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").expect("Invalid regex");
-
-    let robots = input
-        .iter()
-        .map(|i| {
-            let m = re
-                .captures(i)
-                .unwrap_or_else(|| panic!("Unable to parse {}", i));
-            let px = m.get(1).unwrap().as_str().parse().unwrap();
-            let py = m.get(2).unwrap().as_str().parse().unwrap();
-            let vx = m.get(3).unwrap().as_str().parse().unwrap();
-            let vy = m.get(4).unwrap().as_str().parse().unwrap();
-
-            Robot {
+fn parse_map(input: &PuzzleInput, width: i32, height: i32) -> Result<Map, ParseError> {
+    let robots = lines(input)
+        .map(|line| {
+            let [px, py, vx, vy] = line.strip_prefix("p=")?.ints_n()?;
+
+            Ok(Robot {
                 position: Position::new(px, py),
                 velocity: Velocity::new(vx, vy),
-            }
+            })
         })
-        .collect::<Vec<Robot>>();
+        .collect::<Result<Vec<Robot>, ParseError>>()?;
 
-    Map {
+    Ok(Map {
         width,
         height,
         robots,
-    }
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use aoc_common::parse_test_input;
+    use aoc_common::{get_input, parse_test_input};
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn test_input() -> Vec<String> {
+    fn test_input() -> PuzzleInput {
         parse_test_input(
             "
             p=0,4 v=3,-3
@@ -224,13 +216,13 @@ mod tests {
     }
 
     #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day14.txt")
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day14.txt")
     }
 
     #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let mut map = parse_map(&test_input, 11, 7);
+    fn test_p1(test_input: PuzzleInput) {
+        let mut map = parse_map(&test_input, 11, 7).unwrap();
         map.tick(100);
 
         let res = map.get_safety_factor();
@@ -239,8 +231,20 @@ mod tests {
     }
 
     #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let mut map = parse_map(&puzzle_input, 101, 103);
+    fn test_p1_with_params(test_input: PuzzleInput) {
+        let mut params = Params::new(Day14::PARAMS);
+        params.set("width", 11).unwrap();
+        params.set("height", 7).unwrap();
+
+        let input = Day14::parse_with_params(&test_input, &params).unwrap();
+        let res = Day14::part1(&input);
+
+        assert_eq!(res, Answer::Int(12));
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let mut map = parse_map(&puzzle_input, 101, 103).unwrap();
         map.tick(100);
 
         let res = map.get_safety_factor();
@@ -249,8 +253,8 @@ mod tests {
     }
 
     #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
-        let mut map = parse_map(&puzzle_input, 101, 103);
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let mut map = parse_map(&puzzle_input, 101, 103).unwrap();
 
         let res = get_tick_least_deviation(&mut map);
 
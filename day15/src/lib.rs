@@ -0,0 +1,264 @@
+use std::fmt::Display;
+
+use aoc_common::{blocks, Answer, Direction, Grid, ParseError, Point, PuzzleInput, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = (Map, Vec<Direction>);
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((map, instrs): &Self::Input<'_>) -> Answer {
+        let mut map = map.clone();
+        map.run(instrs);
+
+        map.sum_gps_coords().into()
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[tracing::instrument(skip_all)]
+fn parse(input: &PuzzleInput) -> Result<(Map, Vec<Direction>), ParseError> {
+    let mut blocks = blocks(input);
+    let (Some(layout), Some(moves)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::input(
+            "a blank line between the map and the moves",
+        ));
+    };
+
+    let chars = Grid::try_parse(
+        layout,
+        |c| "#O.@".contains(c).then_some(c),
+        "\"#\", \"O\", \".\" or \"@\"",
+    )?;
+    let robot = chars
+        .find(|&c| c == '@')
+        .ok_or_else(|| ParseError::input("a robot \"@\" in the map"))?;
+    let tiles = chars.map(|&c| match c {
+        '#' => Tile::Wall,
+        'O' => Tile::Box,
+        _ => Tile::Empty,
+    });
+
+    let map = Map { robot, tiles };
+
+    let mut instructions = Vec::new();
+    for line in moves {
+        for (i, c) in line.text().char_indices() {
+            let dir = Direction::try_from(c).map_err(|_| {
+                line.error(&line.text()[i..], "a move: \"^\", \">\", \"v\" or \"<\"")
+            })?;
+            instructions.push(dir);
+        }
+    }
+
+    Ok((map, instructions))
+}
+
+type Position = Point<usize>;
+
+#[derive(Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    robot: Position,
+}
+
+impl Map {
+    #[tracing::instrument(skip_all)]
+    fn run(&mut self, instrs: &[Direction]) {
+        // println!("{}", self);
+
+        for d in instrs {
+            let dst = self.robot.checked_offset(d.delta());
+            let Some(dst) = dst.filter(|&p| self.tiles.contains(p)) else {
+                continue;
+            };
+
+            if self.tiles[dst] == Tile::Empty {
+                self.robot = dst;
+            } else if let Some(e) = self.find_next_empty(d) {
+                self.robot = dst;
+
+                self.tiles[dst] = Tile::Empty;
+                self.tiles[e] = Tile::Box;
+            }
+
+            // println!("Move: {:?}", d);
+            // println!("{}\n", self);
+        }
+    }
+
+    fn sum_gps_coords(&self) -> usize {
+        self.tiles
+            .positions_of(&Tile::Box)
+            .map(|p| p.x + 100 * p.y)
+            .sum()
+    }
+
+    fn find_next_empty(&self, dir: &Direction) -> Option<Position> {
+        let mut p = self.robot;
+
+        loop {
+            p = p.checked_offset(dir.delta())?;
+
+            match self.tiles.get(p)? {
+                Tile::Box => {
+                    continue;
+                }
+                Tile::Wall => {
+                    return None;
+                }
+                Tile::Empty => {
+                    return Some(p);
+                }
+            };
+        }
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut chars = self.tiles.map(Tile::as_char);
+        chars[self.robot] = '@';
+
+        write!(f, "{}", chars)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tile {
+    Box,
+    Wall,
+    Empty,
+}
+
+impl Tile {
+    fn as_char(&self) -> char {
+        match self {
+            Tile::Box => 'O',
+            Tile::Wall => '#',
+            Tile::Empty => ' ',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn small_test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+            ########
+            #..O.O.#
+            ##@.O..#
+            #...O..#
+            #.#.O..#
+            #...O..#
+            #......#
+            ########
+
+            <^^>>>vv<v>>v<<
+        ",
+        )
+    }
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+            ##########
+            #..O..O.O#
+            #......O.#
+            #.OO..O.O#
+            #..O@..O.#
+            #O#..O...#
+            #O..O..O.#
+            #.OO.O.OO#
+            #....O...#
+            ##########
+
+            <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+            vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+            ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+            <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+            ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+            ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+            >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+            <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+            ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+            v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+        ",
+        )
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day15.txt")
+    }
+
+    #[rstest]
+    fn test_p1_small(small_test_input: PuzzleInput) {
+        let (mut map, instrs) = parse(&small_test_input).unwrap();
+        map.run(&instrs);
+
+        let res = map.sum_gps_coords();
+
+        assert_eq!(res, 2028);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let (mut map, instrs) = parse(&test_input).unwrap();
+        map.run(&instrs);
+
+        let res = map.sum_gps_coords();
+
+        assert_eq!(res, 10092);
+    }
+
+    #[rstest]
+    #[case::no_separator("#@.#\n<>", 0, 0)]
+    #[case::bad_tile("#@x#\n\n<>", 1, 3)]
+    #[case::no_robot("#..#\n\n<>", 0, 0)]
+    #[case::bad_move("#@.#\n\n<>\n>>x<", 4, 3)]
+    fn test_parse_invalid(#[case] input: &str, #[case] line: usize, #[case] column: usize) {
+        let err = parse(&PuzzleInput::from(input)).err().unwrap();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let (mut map, instrs) = parse(&puzzle_input).unwrap();
+        map.run(&instrs);
+
+        let res = map.sum_gps_coords();
+
+        assert_eq!(res, 1437174);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+
+    #[rstest]
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+}
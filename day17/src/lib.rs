@@ -1,62 +1,61 @@
-use std::fmt::Display;
-use std::time::Instant;
-
-use aoc_common::{format_duration, get_input, tracing_init};
+use aoc_common::{lines, Answer, Line, ParseError, PuzzleInput, Solution};
 use itertools::Itertools;
 use tracing::debug;
 
-fn main() {
-    tracing_init();
-
-    let input = get_input("day17.txt");
-
-    let start = Instant::now();
-
-    let (r1, r2) = solve(input.as_slice());
+pub struct Day17;
 
-    let t = start.elapsed().as_nanos();
+impl Solution for Day17 {
+    type Input<'a> = Machine;
 
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
-
-#[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let mut m = parse_machine(input);
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse_machine(input)
+    }
 
-    let p1 = m.get_output();
-    let p2 = 0;
+    fn part1(machine: &Self::Input<'_>) -> Answer {
+        machine.clone().get_output().into()
+    }
 
-    (p1, p2)
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
 }
 
 #[tracing::instrument(skip_all)]
-fn parse_machine(input: &[String]) -> Machine {
-    let a = input[0].split(": ").last().unwrap().parse::<u64>().unwrap();
-    let b = input[1].split(": ").last().unwrap().parse::<u64>().unwrap();
-    let c = input[2].split(": ").last().unwrap().parse::<u64>().unwrap();
-
-    let program = input[4]
-        .split(": ")
-        .last()
-        .unwrap()
+fn parse_machine(input: &PuzzleInput) -> Result<Machine, ParseError> {
+    let lines = lines(input).collect::<Vec<Line>>();
+    let field = |idx: usize, name: &str| {
+        lines
+            .get(idx)
+            .ok_or_else(|| ParseError::input(format!("a {:?} line", name)))?
+            .strip_prefix(&format!("{}: ", name))
+    };
+
+    let a = field(0, "Register A")?.value("a number")?;
+    let b = field(1, "Register B")?.value("a number")?;
+    let c = field(2, "Register C")?.value("a number")?;
+
+    let program = field(4, "Program")?;
+    let program = program
+        .text()
         .split(',')
-        .map(|i| i.parse::<u8>().unwrap())
-        .collect();
+        .map(|i| match program.parse::<u8>(i, "a 3-bit number") {
+            Ok(n) if n > 7 => Err(program.error(i, "a 3-bit number")),
+            res => res,
+        })
+        .collect::<Result<_, _>>()?;
 
-    Machine {
+    Ok(Machine {
         a,
         b,
         c,
         program,
         pc: 0,
         output: Vec::new(),
-    }
+    })
 }
 
-#[derive(Default, Debug)]
-struct Machine {
+#[derive(Default, Debug, Clone)]
+pub struct Machine {
     a: u64,
     b: u64,
     c: u64,
@@ -189,13 +188,13 @@ impl Machine {
 
 #[cfg(test)]
 mod tests {
-    use aoc_common::parse_test_input;
+    use aoc_common::{get_input, parse_test_input};
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn test_input() -> Vec<String> {
+    fn test_input() -> PuzzleInput {
         parse_test_input(
             "
             Register A: 729
@@ -208,35 +207,46 @@ mod tests {
     }
 
     #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day17.txt")
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day17.txt")
     }
 
     #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let mut m = parse_machine(&test_input);
+    fn test_p1(test_input: PuzzleInput) {
+        let mut m = parse_machine(&test_input).unwrap();
         let res = m.get_output();
 
         assert_eq!(res, "4,6,3,5,6,3,5,2,1,0");
     }
 
     #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let mut m = parse_machine(&puzzle_input);
+    #[case::missing_line("Register A: 1\nRegister B: 0", 0, 0)]
+    #[case::bad_label("Register A: 1\nRegister X: 0\nRegister C: 0\n\nProgram: 0", 2, 1)]
+    #[case::bad_register("Register A: -1\nRegister B: 0\nRegister C: 0\n\nProgram: 0", 1, 13)]
+    #[case::bad_opcode("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8", 5, 12)]
+    fn test_parse_machine_invalid(#[case] input: &str, #[case] line: usize, #[case] column: usize) {
+        let err = parse_machine(&PuzzleInput::from(input)).unwrap_err();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let mut m = parse_machine(&puzzle_input).unwrap();
         let res = m.get_output();
 
         assert_eq!(res, "6,0,6,3,0,2,3,1,6");
     }
 
     #[rstest]
-    fn test_p2(test_input: Vec<String>) {
+    fn test_p2(test_input: PuzzleInput) {
         let res = 0;
 
         assert_eq!(res, 1);
     }
 
     #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
         let res = 0;
 
         assert_eq!(res, 1);
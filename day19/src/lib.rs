@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+
+use aoc_common::{lines, Answer, Line, ParseError, PuzzleInput, Solution};
+use tracing::debug;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Puzzle<'a>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse_puzzle(input)
+    }
+
+    fn part1(puzzle: &Self::Input<'_>) -> Answer {
+        puzzle.count_possible_designs().into()
+    }
+
+    fn part2(puzzle: &Self::Input<'_>) -> Answer {
+        puzzle.count_all_builds().into()
+    }
+}
+
+#[tracing::instrument(skip_all)]
+fn parse_puzzle(input: &PuzzleInput) -> Result<Puzzle<'_>, ParseError> {
+    let mut lines = lines(input);
+
+    let first = lines
+        .next()
+        .ok_or_else(|| ParseError::input("a line of towel patterns"))?;
+    let patterns = first
+        .text()
+        .split(", ")
+        .map(|p| stripes(first, p))
+        .collect::<Result<_, _>>()?;
+
+    match lines.next() {
+        Some(line) if !line.text().is_empty() => {
+            return Err(line.error(line.text(), "a blank line"))
+        }
+        Some(_) => {}
+        None => return Err(first.error_at_end("a blank line after the towel patterns")),
+    }
+
+    let designs = lines
+        .map(|line| stripes(line, line.text()))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Puzzle { patterns, designs })
+}
+
+/// `token`, a slice of `line`, if it is a non-empty sequence of stripe colors.
+fn stripes<'a>(line: Line<'a>, token: &'a str) -> Result<&'a str, ParseError> {
+    const EXPECTED: &str = "a stripe color: \"w\", \"u\", \"b\", \"r\" or \"g\"";
+
+    if token.is_empty() {
+        return Err(line.error(token, EXPECTED));
+    }
+    match token.char_indices().find(|(_, c)| !"wubrg".contains(*c)) {
+        Some((i, _)) => Err(line.error(&token[i..], EXPECTED)),
+        None => Ok(token),
+    }
+}
+
+pub struct Puzzle<'a> {
+    patterns: Vec<&'a str>,
+    designs: Vec<&'a str>,
+}
+
+impl Puzzle<'_> {
+    #[tracing::instrument(skip_all)]
+    fn count_possible_designs(&self) -> usize {
+        self.builds_per_design().filter(|&n| n > 0).count()
+    }
+
+    #[tracing::instrument(skip_all)]
+    fn count_all_builds(&self) -> usize {
+        self.builds_per_design().sum()
+    }
+
+    /// The number of ways to build each design.
+    fn builds_per_design(&self) -> impl Iterator<Item = usize> + '_ {
+        self.designs.iter().enumerate().map(|(idx, d)| {
+            debug!(
+                "Checking design {} ({} of {})",
+                d,
+                idx + 1,
+                self.designs.len()
+            );
+
+            self.count_builds_for_design(d)
+        })
+    }
+
+    fn count_builds_for_design(&self, design: &str) -> usize {
+        let mut count = 0;
+        debug!("Testing for design: {}", design);
+
+        // Lengths of the prefixes of the design built so far, with the number of ways to build them.
+        let mut candidates = VecDeque::new();
+        candidates.push_back((0, 1));
+
+        let useful_patterns: Vec<&str> = self
+            .patterns
+            .iter()
+            .copied()
+            .filter(|p| design.contains(p))
+            .collect();
+
+        while let Some((c, n)) = candidates.pop_front() {
+            for p in &useful_patterns {
+                if !design[c..].starts_with(p) {
+                    continue;
+                }
+
+                let d = c + p.len();
+                if d == design.len() {
+                    count += n;
+                } else if let Some(idx) = candidates.iter().position(|(c, _)| c == &d) {
+                    let (d, n2) = candidates.remove(idx).unwrap();
+                    candidates.push_back((d, n2 + n))
+                } else {
+                    candidates.push_back((d, n));
+                }
+            }
+        }
+
+        if count > 0 {
+            debug!("{} can be made in {} ways\n", design, count);
+        } else {
+            debug!("{} can't be made\n", design);
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+            r, wr, b, g, bwu, rb, gb, br
+
+            brwrr
+            bggr
+            gbbr
+            rrbgbr
+            ubwu
+            bwurrg
+            brgr
+            bbrgwb
+        ",
+        )
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day19.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let puzzle = parse_puzzle(&test_input).unwrap();
+        let res = puzzle.count_possible_designs();
+
+        assert_eq!(res, 6);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let puzzle = parse_puzzle(&test_input).unwrap();
+        let res = puzzle.count_all_builds();
+
+        assert_eq!(res, 16);
+    }
+
+    #[rstest]
+    #[ignore]
+    fn test_full_input(puzzle_input: PuzzleInput) {
+        let puzzle = parse_puzzle(&puzzle_input).unwrap();
+        assert_eq!(puzzle.count_possible_designs(), 290);
+        assert_eq!(puzzle.count_all_builds(), 712058625427487);
+    }
+
+    #[rstest]
+    #[case("brwrr", 2)]
+    #[case("bggr", 1)]
+    #[case("gbbr", 4)]
+    #[case("rrbgbr", 6)]
+    #[case("bwurrg", 1)]
+    #[case("brgr", 2)]
+    #[case("ubwu", 0)]
+    #[case("bbrgwb", 0)]
+    fn test_count_possibilities(#[case] design: &'static str, #[case] count: usize) {
+        let puzzle = Puzzle {
+            patterns: vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"],
+            designs: Vec::new(),
+        };
+
+        assert_eq!(puzzle.count_builds_for_design(design), count);
+    }
+}
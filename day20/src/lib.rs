@@ -0,0 +1,63 @@
+use aoc_common::{Answer, ParseError, PuzzleInput, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = ();
+
+    fn parse(_input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Ok(())
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input, PuzzleInput};
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input("")
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day20.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+
+    #[rstest]
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+}
@@ -1,33 +1,39 @@
 use std::collections::HashMap;
-use std::fmt::Display;
-use std::time::Instant;
 
-use aoc_common::{format_duration, get_input, tracing_init};
+use aoc_common::{lines, Answer, Param, Params, ParseError, PuzzleInput, Solution};
 
-fn main() {
-    tracing_init();
+pub struct Day22;
 
-    let input = get_input("day22.txt");
+impl Solution for Day22 {
+    type Input<'a> = (Vec<u64>, u32);
 
-    let start = Instant::now();
+    const PARAMS: &'static [Param] = &[Param::new("iterations", 2000, 0..=10000)];
 
-    let (r1, r2) = solve(input.as_slice());
-
-    let t = start.elapsed().as_nanos();
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with_params(input, &Params::new(Self::PARAMS))
+    }
 
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
-}
+    fn parse_with_params<'a>(
+        input: &'a PuzzleInput,
+        params: &Params,
+    ) -> Result<Self::Input<'a>, ParseError> {
+        let secrets = lines(input)
+            .map(|line| line.value("a secret number"))
+            .collect::<Result<Vec<u64>, _>>()?;
+        if secrets.is_empty() {
+            return Err(ParseError::input("at least one secret number"));
+        }
 
-#[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let input: Vec<u64> = input.iter().map(|i| i.parse::<u64>().unwrap()).collect();
+        Ok((secrets, params.try_get("iterations")?))
+    }
 
-    let p1 = get_sum_of_secret_numbers(&input, 2000);
-    let p2 = get_most_bananas(&input, 2000);
+    fn part1((secrets, iterations): &Self::Input<'_>) -> Answer {
+        get_sum_of_secret_numbers(secrets, *iterations).into()
+    }
 
-    (p1, p2)
+    fn part2((secrets, iterations): &Self::Input<'_>) -> Answer {
+        get_most_bananas(secrets, *iterations).into()
+    }
 }
 
 const MODULO: u64 = 16777215;
@@ -69,7 +75,8 @@ fn get_most_bananas(input: &[u64], iterations: u32) -> u64 {
         }
     }
 
-    *totals.values().max().unwrap()
+    // There are no sequences with less than 4 price changes.
+    totals.values().max().copied().unwrap_or(0)
 }
 
 fn get_price_lists(input: &[u64], iterations: u32) -> Vec<Vec<u8>> {
@@ -125,14 +132,15 @@ fn get_sequences(price_lists: &Vec<Vec<u8>>) -> Vec<HashMap<Vec<i8>, u8>> {
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::get_input;
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
     fn puzzle_input() -> Vec<u64> {
-        get_input("day22.txt")
-            .iter()
+        get_input("2024/day22.txt")
+            .lines()
             .map(|i| i.parse::<u64>().unwrap())
             .collect()
     }
@@ -160,6 +168,22 @@ mod tests {
         assert_eq!(res, 23);
     }
 
+    #[rstest]
+    fn test_p2_without_sequences() {
+        let res = get_most_bananas(&[1, 2, 3, 2024], 3);
+
+        assert_eq!(res, 0);
+    }
+
+    #[rstest]
+    #[case::empty("", 0, 0)]
+    #[case::bad_number("1\n2x\n3", 2, 1)]
+    fn test_parse_invalid(#[case] input: &str, #[case] line: usize, #[case] column: usize) {
+        let err = Day22::parse(&PuzzleInput::from(input)).err().unwrap();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+
     #[rstest]
     #[ignore]
     fn test_p2_full_input(puzzle_input: Vec<u64>) {
@@ -1,72 +1,99 @@
 use std::collections::HashMap;
-use std::fmt::Display;
-use std::time::Instant;
 
-use aoc_common::{format_duration, get_input, tracing_init};
+use aoc_common::{blocks, Answer, Line, ParseError, PuzzleInput, Solution};
 use itertools::Itertools;
 use tracing::debug;
 
-fn main() {
-    tracing_init();
+pub struct Day24;
 
-    let input = get_input("day24.txt");
+impl Solution for Day24 {
+    type Input<'a> = System;
 
-    let start = Instant::now();
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        parse_system(input)
+    }
 
-    let (r1, r2) = solve(input.as_slice());
+    fn part1(sys: &Self::Input<'_>) -> Answer {
+        let mut sys = sys.clone();
+        sys.solve();
 
-    let t = start.elapsed().as_nanos();
+        sys.get_z_wires_value().into()
+    }
 
-    println!("Part 1: {}", r1);
-    println!("Part 2: {}", r2);
-    println!("Duration: {}", format_duration(t));
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
 }
 
 #[tracing::instrument(skip_all)]
-fn solve(input: &[String]) -> (impl Display, impl Display) {
-    let mut sys = parse_system(input);
-    sys.solve();
+fn parse_system(input: &PuzzleInput) -> Result<System, ParseError> {
+    let mut blocks = blocks(input);
+    let (Some(values), Some(gates)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::input(
+            "a blank line between the wire values and the gates",
+        ));
+    };
+
+    let variables = values
+        .into_iter()
+        .map(|line| {
+            let (name, value) = line.split_pair(": ")?;
+
+            match value.text() {
+                "0" | "1" => Ok((name.text().to_owned(), value.value("0 or 1")?)),
+                _ => Err(value.error(value.text(), "0 or 1")),
+            }
+        })
+        .collect::<Result<HashMap<String, u8>, ParseError>>()?;
 
-    let p1 = sys.get_z_wires_value();
-    let p2 = 0;
+    let wires = gates
+        .into_iter()
+        .map(parse_wire)
+        .collect::<Result<_, _>>()?;
 
-    (p1, p2)
+    Ok(System { variables, wires })
 }
 
-#[tracing::instrument(skip_all)]
-fn parse_system(input: &[String]) -> System {
-    let (values, equations) = input.split(|s| s.is_empty()).collect_tuple().unwrap();
-
-    let variables: HashMap<String, u8> = values
-        .iter()
-        .map(|v| {
-            let (name, value) = v.split(": ").collect_tuple().unwrap();
-            (name.to_owned(), value.parse::<u8>().unwrap())
-        })
-        .collect();
-
-    let wires = equations
-        .iter()
-        .map(|v| {
-            let (a, op, b, out) = v
-                .replace("-> ", "")
-                .split(" ")
-                .map(|v| v.to_string())
-                .collect_tuple()
-                .unwrap();
-            Wire { a, b, op, out }
-        })
-        .collect();
+/// Parse a gate such as `x00 AND y00 -> z00`.
+fn parse_wire(line: Line) -> Result<Wire, ParseError> {
+    let mut tokens = line.text().split(' ');
+    let mut next = |expected: &str| {
+        tokens
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or_else(|| line.error_at_end(expected))
+    };
+
+    let a = next("a wire")?;
+    let op = next("an operation")?;
+    if !["AND", "OR", "XOR"].contains(&op) {
+        return Err(line.error(op, "\"AND\", \"OR\" or \"XOR\""));
+    }
+    let b = next("a wire")?;
+    let arrow = next("\"->\"")?;
+    if arrow != "->" {
+        return Err(line.error(arrow, "\"->\""));
+    }
+    let out = next("a wire")?;
+    if let Some(extra) = tokens.next() {
+        return Err(line.error(extra, "the end of the line"));
+    }
 
-    System { variables, wires }
+    Ok(Wire {
+        a: a.to_owned(),
+        b: b.to_owned(),
+        op: op.to_owned(),
+        out: out.to_owned(),
+    })
 }
 
-struct System {
+#[derive(Clone)]
+pub struct System {
     variables: HashMap<String, u8>,
     wires: Vec<Wire>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Wire {
     a: String,
     b: String,
@@ -125,13 +152,13 @@ impl System {
 
 #[cfg(test)]
 mod tests {
-    use aoc_common::parse_test_input;
+    use aoc_common::{get_input, parse_test_input};
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn test_input() -> Vec<String> {
+    fn test_input() -> PuzzleInput {
         parse_test_input(
             "
             x00: 1
@@ -186,13 +213,13 @@ mod tests {
     }
 
     #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day24.txt")
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day24.txt")
     }
 
     #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let mut sys = parse_system(&test_input);
+    fn test_p1(test_input: PuzzleInput) {
+        let mut sys = parse_system(&test_input).unwrap();
         sys.solve();
 
         let res = sys.get_z_wires_value();
@@ -201,8 +228,19 @@ mod tests {
     }
 
     #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let mut sys = parse_system(&puzzle_input);
+    #[case::no_separator("x00: 1\nx00 AND y00 -> z00", 0, 0)]
+    #[case::bad_value("x00: 2\n\nx00 AND y00 -> z00", 1, 6)]
+    #[case::bad_op("x00: 1\n\nx00 NAND y00 -> z00", 3, 5)]
+    #[case::missing_output("x00: 1\n\nx00 AND y00 ->", 3, 15)]
+    fn test_parse_system_invalid(#[case] input: &str, #[case] line: usize, #[case] column: usize) {
+        let err = parse_system(&PuzzleInput::from(input)).err().unwrap();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let mut sys = parse_system(&puzzle_input).unwrap();
         sys.solve();
 
         let res = sys.get_z_wires_value();
@@ -211,14 +249,14 @@ mod tests {
     }
 
     #[rstest]
-    fn test_p2(test_input: Vec<String>) {
+    fn test_p2(test_input: PuzzleInput) {
         let res = 0;
 
         assert_eq!(res, 1);
     }
 
     #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
         let res = 0;
 
         assert_eq!(res, 1);
@@ -0,0 +1,200 @@
+use aoc_common::{blocks, Answer, ParseError, PuzzleInput, Solution};
+use itertools::Itertools;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Schematics;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input<'_>, ParseError> {
+        Schematics::try_from(input)
+    }
+
+    fn part1(schematics: &Self::Input<'_>) -> Answer {
+        schematics.get_arrangements().into()
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematics {
+    locks: Vec<Vec<u8>>,
+    keys: Vec<Vec<u8>>,
+}
+
+impl Schematics {
+    #[tracing::instrument(skip_all)]
+    fn get_arrangements(&self) -> usize {
+        self.keys
+            .iter()
+            .cartesian_product(&self.locks)
+            .filter(|(k, l)| k.iter().zip(l.iter()).all(|(a, b)| a + b <= 5))
+            .count()
+    }
+}
+
+impl TryFrom<&PuzzleInput> for Schematics {
+    type Error = ParseError;
+
+    #[tracing::instrument(skip_all)]
+    fn try_from(values: &PuzzleInput) -> Result<Self, Self::Error> {
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+
+        for chunk in blocks(values) {
+            if let Some(extra) = chunk.get(7) {
+                return Err(extra.error(extra.text(), "a blank line after 7 rows"));
+            }
+            if let Some(last) = chunk.last().filter(|_| chunk.len() < 7) {
+                return Err(last.error_at_end("7 rows of 5 \"#\" or \".\""));
+            }
+            for line in &chunk {
+                if let Some((i, _)) = line.text().char_indices().find(|(_, c)| !"#.".contains(*c)) {
+                    return Err(line.error(&line.text()[i..], "\"#\" or \".\""));
+                }
+                match line.text().get(5..) {
+                    None => return Err(line.error_at_end("5 \"#\" or \".\"")),
+                    Some(extra) if !extra.is_empty() => {
+                        return Err(line.error(extra, "the end of the line"));
+                    }
+                    _ => {}
+                }
+            }
+
+            if chunk[0].text() != "#####" && chunk[6].text() != "#####" {
+                return Err(chunk[0].error(
+                    chunk[0].text(),
+                    "a lock, with a full top row, or a key, with a full bottom row",
+                ));
+            }
+
+            let heights: Vec<u8> = (0usize..5)
+                .map(|i| {
+                    (chunk
+                        .iter()
+                        .filter_map(|s| {
+                            if let Some("#") = s.text().get(i..=i) {
+                                Some(())
+                            } else {
+                                None
+                            }
+                        })
+                        .count()
+                        - 1) as u8
+                })
+                .collect();
+
+            if chunk[0].text() == "#####" {
+                locks.push(heights);
+            } else {
+                keys.push(heights);
+            }
+        }
+
+        Ok(Self { locks, keys })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_input, parse_test_input};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn test_input() -> PuzzleInput {
+        parse_test_input(
+            "
+            #####
+            .####
+            .####
+            .####
+            .#.#.
+            .#...
+            .....
+
+            #####
+            ##.##
+            .#.##
+            ...##
+            ...#.
+            ...#.
+            .....
+
+            .....
+            #....
+            #....
+            #...#
+            #.#.#
+            #.###
+            #####
+
+            .....
+            .....
+            #.#..
+            ###..
+            ###.#
+            ###.#
+            #####
+
+            .....
+            .....
+            .....
+            #....
+            #.#..
+            #.#.#
+            #####
+        ",
+        )
+    }
+
+    #[fixture]
+    fn puzzle_input() -> PuzzleInput {
+        get_input("2024/day25.txt")
+    }
+
+    #[rstest]
+    fn test_p1(test_input: PuzzleInput) {
+        let schematics = Schematics::try_from(&test_input).unwrap();
+        let res = schematics.get_arrangements();
+
+        assert_eq!(res, 3);
+    }
+
+    #[rstest]
+    #[case::bad_char("#####\n.#x..\n.....\n.....\n.....\n.....\n.....", 2, 3)]
+    #[case::short_row("#####\n.#..\n.....\n.....\n.....\n.....\n.....", 2, 5)]
+    #[case::missing_rows("#####\n.....", 2, 6)]
+    #[case::neither(".....\n.....\n.....\n.....\n.....\n.....\n.....", 1, 1)]
+    fn test_parse_invalid(#[case] input: &str, #[case] line: usize, #[case] column: usize) {
+        let err = Schematics::try_from(&PuzzleInput::from(input)).unwrap_err();
+
+        assert_eq!((err.line, err.column), (line, column));
+    }
+
+    #[rstest]
+    fn test_p1_full_input(puzzle_input: PuzzleInput) {
+        let schematics = Schematics::try_from(&puzzle_input).unwrap();
+        let res = schematics.get_arrangements();
+
+        assert_eq!(res, 3307);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+
+    #[rstest]
+    fn test_p2_full_input(puzzle_input: PuzzleInput) {
+        let res = 0;
+
+        assert_eq!(res, 1);
+    }
+}